@@ -0,0 +1,27 @@
+// Copyright (c) 2023-2024, Johan Thorén.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+use std::hint::black_box;
+
+use chrono::{DateTime, Utc};
+use criterion::{criterion_group, criterion_main, Criterion};
+use solar_calendar_events::{AnnualSolarEvent, AnnualSolarEvents, JulianDateTimeUtc, MarchEquinox};
+
+fn conversions(c: &mut Criterion) {
+    c.bench_function("DateTime::from_julian_day", |b| {
+        b.iter(|| DateTime::<Utc>::from_julian_day(black_box(2_451_623.809_843_217)))
+    });
+    c.bench_function("MarchEquinox::for_year", |b| {
+        b.iter(|| MarchEquinox::for_year(black_box(2024)))
+    });
+    c.bench_function("AnnualSolarEvents::for_year", |b| {
+        b.iter(|| AnnualSolarEvents::for_year(black_box(2024)))
+    });
+}
+
+criterion_group!(benches, conversions);
+criterion_main!(benches);
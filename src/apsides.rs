@@ -0,0 +1,278 @@
+// Copyright (c) 2023-2024, Johan Thorén.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The perihelion and aphelion of the Earth, following Meeus, *Astronomical Algorithms* (2nd ed.),
+//! chapter 38.
+
+use chrono::{DateTime, Datelike, Utc};
+
+use crate::{delta_t, vsop87, AnnualSolarEventError, CalculationMethod, JulianDateTimeUtc};
+
+/// The number of golden-section steps when refining an apsis with VSOP87, which narrows the
+/// search interval of a few days to well below a second.
+const GOLDEN_SECTION_STEPS: usize = 60;
+
+/// The two apsides of the orbit of the Earth.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Apsis {
+    Perihelion,
+    Aphelion,
+}
+
+impl Apsis {
+    /// Calculates the Julian Ephemeris Day and the Earth–Sun distance of the apsis in a given
+    /// calendar year.
+    ///
+    /// Returns an error if the year is outside the range of `method`, or if no such apsis falls
+    /// in the year.
+    fn calculate(
+        &self,
+        year: i32,
+        method: CalculationMethod,
+    ) -> Result<(f64, f64), AnnualSolarEventError> {
+        if !method.year_range().contains(&year) {
+            return Err(AnnualSolarEventError::YearOutOfRange(year));
+        }
+        // The perihelion nearest the start of the year falls in the previous year before about
+        // 1880, so the next apsis is tried as well, and the previous one for good measure.
+        let nearest = (0.999_97 * (year as f64 - 2_000.01)).round()
+            + match self {
+                Apsis::Perihelion => 0.0,
+                Apsis::Aphelion => 0.5,
+            };
+        for k in [nearest, nearest + 1.0, nearest - 1.0] {
+            let jde = match method {
+                CalculationMethod::Meeus => self.meeus_julian_ephemeris_day(k),
+                CalculationMethod::TruncatedVsop87 => {
+                    self.refine(self.meeus_julian_ephemeris_day(k))
+                }
+            };
+            let julian_day = delta_t::universal_julian_day(jde);
+            if DateTime::<Utc>::from_julian_day(julian_day)?.year() == year {
+                let (_, _, distance) = vsop87::geometric_sun(jde);
+                return Ok((jde, distance));
+            }
+        }
+        Err(AnnualSolarEventError::NoEventInYear(year))
+    }
+
+    /// Calculates the instant of the apsis with the mean orbit of the Earth–Moon barycenter,
+    /// corrected for the perturbations by the Moon and the planets (Meeus, equation 38.3 and the
+    /// corrections for the Earth).
+    fn meeus_julian_ephemeris_day(&self, k: f64) -> f64 {
+        let jde = 2_451_547.507 + 365.259_635_8 * k + 0.000_000_015_6 * k * k;
+        let a = [
+            328.41 + 132.788_585 * k,
+            316.13 + 584.903_153 * k,
+            346.20 + 450.380_738 * k,
+            136.95 + 659.306_737 * k,
+            249.52 + 329.653_368 * k,
+        ];
+        let coefficients = match self {
+            Apsis::Perihelion => [1.278, -0.055, -0.091, -0.056, -0.045],
+            Apsis::Aphelion => [-1.352, 0.061, 0.062, 0.029, 0.031],
+        };
+
+        jde + coefficients
+            .iter()
+            .zip(a)
+            .map(|(coefficient, a)| coefficient * a.to_radians().sin())
+            .sum::<f64>()
+    }
+
+    /// Refines the instant of the apsis to the extremum of the Earth–Sun distance from VSOP87,
+    /// searching within three days of an estimate.
+    fn refine(&self, estimate: f64) -> f64 {
+        let sign = match self {
+            Apsis::Perihelion => 1.0,
+            Apsis::Aphelion => -1.0,
+        };
+        let distance = |jde: f64| sign * vsop87::geometric_sun(jde).2;
+        let ratio = (5.0_f64.sqrt() - 1.0) / 2.0;
+
+        let (mut low, mut high) = (estimate - 3.0, estimate + 3.0);
+        for _ in 0..GOLDEN_SECTION_STEPS {
+            let a = high - ratio * (high - low);
+            let b = low + ratio * (high - low);
+            if distance(a) < distance(b) {
+                high = b;
+            } else {
+                low = a;
+            }
+        }
+        (low + high) / 2.0
+    }
+}
+
+/// Represents the perihelion of the Earth, when it is closest to the Sun, for a specific year.
+///
+/// This is the perihelion that falls in the calendar year. The anomalistic year is about 25
+/// minutes longer than the calendar year, so the perihelion drifts later in the calendar by 1.7
+/// days a century. It falls in early January today and at the end of the year before about 1770,
+/// so around 1800 some years have no perihelion at all, while others have two. For those, the
+/// one nearer to the start of the year is used.
+#[derive(Debug)]
+pub struct Perihelion {
+    year: i32,
+    julian_day: f64,
+    julian_ephemeris_day: f64,
+    date_time: DateTime<Utc>,
+    distance: f64,
+}
+
+impl Perihelion {
+    /// Creates the perihelion for the specified year, using the default calculation method.
+    ///
+    /// Returns an error if the year is outside the valid range, or if no perihelion falls in the
+    /// year.
+    ///
+    /// # Example
+    /// ```
+    /// use solar_calendar_events::Perihelion;
+    ///
+    /// let perihelion = Perihelion::for_year(2024).unwrap();
+    ///
+    /// assert_eq!(perihelion.date_time().format("%F").to_string(), "2024-01-03");
+    /// assert!((perihelion.distance() - 0.983_3).abs() < 0.000_1);
+    /// ```
+    pub fn for_year(year: i32) -> Result<Self, AnnualSolarEventError> {
+        Self::for_year_with_method(year, CalculationMethod::default())
+    }
+
+    /// Creates the perihelion for the specified year, using the given calculation method.
+    ///
+    /// `CalculationMethod::Meeus` uses the mean orbit with the corrections of Meeus, chapter 38,
+    /// accurate to about half a day. `CalculationMethod::TruncatedVsop87` refines it to the
+    /// minimum of the Earth–Sun distance from VSOP87, accurate to within an hour. The distance
+    /// changes very slowly near an apsis, so the instant is far more sensitive to the truncation
+    /// of the theory than the distance itself.
+    ///
+    /// Returns an error if the year is outside the range supported by `method`, or if no
+    /// perihelion falls in the year.
+    pub fn for_year_with_method(
+        year: i32,
+        method: CalculationMethod,
+    ) -> Result<Self, AnnualSolarEventError> {
+        let (julian_ephemeris_day, distance) = Apsis::Perihelion.calculate(year, method)?;
+        let julian_day = delta_t::universal_julian_day(julian_ephemeris_day);
+        Ok(Self {
+            year,
+            julian_day,
+            julian_ephemeris_day,
+            date_time: DateTime::<Utc>::from_julian_day(julian_day)?,
+            distance,
+        })
+    }
+
+    /// Returns the date and time of the perihelion as a `DateTime<Utc>`.
+    pub fn date_time(&self) -> DateTime<Utc> {
+        self.date_time
+    }
+
+    /// Returns the Julian Day Number of the perihelion in Universal Time.
+    pub fn julian_day(&self) -> f64 {
+        self.julian_day
+    }
+
+    /// Returns the Julian Ephemeris Day of the perihelion, i.e. the Julian Day in Terrestrial
+    /// (Dynamical) Time.
+    pub fn julian_ephemeris_day(&self) -> f64 {
+        self.julian_ephemeris_day
+    }
+
+    /// Returns the distance between the Earth and the Sun at perihelion, in astronomical units.
+    pub fn distance(&self) -> f64 {
+        self.distance
+    }
+
+    /// Returns the year for which the perihelion is calculated.
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+}
+
+/// Represents the aphelion of the Earth, when it is farthest from the Sun, for a specific year.
+///
+/// This is the aphelion that falls in the calendar year, in early July today.
+#[derive(Debug)]
+pub struct Aphelion {
+    year: i32,
+    julian_day: f64,
+    julian_ephemeris_day: f64,
+    date_time: DateTime<Utc>,
+    distance: f64,
+}
+
+impl Aphelion {
+    /// Creates the aphelion for the specified year, using the default calculation method.
+    ///
+    /// Returns an error if the year is outside the valid range.
+    ///
+    /// # Example
+    /// ```
+    /// use solar_calendar_events::Aphelion;
+    ///
+    /// let aphelion = Aphelion::for_year(2024).unwrap();
+    ///
+    /// assert_eq!(aphelion.date_time().format("%F").to_string(), "2024-07-05");
+    /// assert!((aphelion.distance() - 1.016_7).abs() < 0.000_1);
+    /// ```
+    pub fn for_year(year: i32) -> Result<Self, AnnualSolarEventError> {
+        Self::for_year_with_method(year, CalculationMethod::default())
+    }
+
+    /// Creates the aphelion for the specified year, using the given calculation method.
+    ///
+    /// `CalculationMethod::Meeus` uses the mean orbit with the corrections of Meeus, chapter 38,
+    /// accurate to about half a day. `CalculationMethod::TruncatedVsop87` refines it to the
+    /// maximum of the Earth–Sun distance from VSOP87, accurate to within an hour. The distance
+    /// changes very slowly near an apsis, so the instant is far more sensitive to the truncation
+    /// of the theory than the distance itself.
+    ///
+    /// Returns an error if the year is outside the range supported by `method`.
+    pub fn for_year_with_method(
+        year: i32,
+        method: CalculationMethod,
+    ) -> Result<Self, AnnualSolarEventError> {
+        let (julian_ephemeris_day, distance) = Apsis::Aphelion.calculate(year, method)?;
+        let julian_day = delta_t::universal_julian_day(julian_ephemeris_day);
+        Ok(Self {
+            year,
+            julian_day,
+            julian_ephemeris_day,
+            date_time: DateTime::<Utc>::from_julian_day(julian_day)?,
+            distance,
+        })
+    }
+
+    /// Returns the date and time of the aphelion as a `DateTime<Utc>`.
+    pub fn date_time(&self) -> DateTime<Utc> {
+        self.date_time
+    }
+
+    /// Returns the Julian Day Number of the aphelion in Universal Time.
+    pub fn julian_day(&self) -> f64 {
+        self.julian_day
+    }
+
+    /// Returns the Julian Ephemeris Day of the aphelion, i.e. the Julian Day in Terrestrial
+    /// (Dynamical) Time.
+    pub fn julian_ephemeris_day(&self) -> f64 {
+        self.julian_ephemeris_day
+    }
+
+    /// Returns the distance between the Earth and the Sun at aphelion, in astronomical units.
+    pub fn distance(&self) -> f64 {
+        self.distance
+    }
+
+    /// Returns the year for which the aphelion is calculated.
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+}
@@ -0,0 +1,269 @@
+// Copyright (c) 2023-2024, Johan Thorén.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Dates in the Julian calendar, and dates in the calendar in use at the time.
+
+use std::fmt;
+
+use chrono::{Datelike, NaiveDate};
+
+use crate::time_utils::{self, Calendar};
+use crate::AnnualSolarEventError;
+
+/// The Julian Day number of 0001-01-01 in the proleptic Gregorian calendar, minus one, which
+/// converts chrono's day count from the Common Era to a Julian Day number.
+const DAYS_FROM_CE_OFFSET: i32 = 1_721_425;
+
+/// The first day of the Gregorian calendar, 1582-10-15, which followed 1582-10-04 in the Julian
+/// calendar.
+const GREGORIAN_REFORM: (i32, u32, u32) = (1_582, 10, 15);
+
+/// The Julian Day number of noon on 1582-10-15, the first day of the Gregorian calendar.
+const FIRST_GREGORIAN_JULIAN_DAY: i32 = 2_299_161;
+
+/// A date in the Julian calendar.
+///
+/// The Julian calendar was in use until the Gregorian reform of 1582, and historical sources from
+/// before the reform are dated in it. Years use astronomical year numbering, so the year 1 BC is
+/// year 0.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct JulianCalendarDate {
+    year: i32,
+    month: u32,
+    day: u32,
+}
+
+impl JulianCalendarDate {
+    /// Creates a date in the Julian calendar.
+    ///
+    /// # Arguments
+    /// * `year` - The year, using astronomical year numbering.
+    /// * `month` - The month (1-12).
+    /// * `day` - The day of the month.
+    ///
+    /// # Returns
+    /// The date in the Julian calendar.
+    ///
+    /// # Errors
+    /// Returns an error if the month is out of range or the day does not exist in the month.
+    pub fn from_ymd(year: i32, month: u32, day: u32) -> Result<Self, AnnualSolarEventError> {
+        let days_in_month = match month {
+            1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+            4 | 6 | 9 | 11 => 30,
+            2 if year.rem_euclid(4) == 0 => 29,
+            2 => 28,
+            _ => return Err(AnnualSolarEventError::MonthOutOfRange(month as i32)),
+        };
+        if !(1..=days_in_month).contains(&day) {
+            return Err(AnnualSolarEventError::InvalidDateError(year, month, day));
+        }
+        Ok(JulianCalendarDate { year, month, day })
+    }
+
+    /// Converts a Julian Day number to the date in the Julian calendar of the day it falls on.
+    ///
+    /// # Arguments
+    /// * `julian_day` - The Julian Day number to convert.
+    ///
+    /// # Returns
+    /// The date in the Julian calendar.
+    ///
+    /// # Errors
+    /// Returns an error if the conversion fails due to invalid date components.
+    ///
+    /// # Example
+    /// ```
+    /// use solar_calendar_events::JulianCalendarDate;
+    ///
+    /// let date = JulianCalendarDate::from_julian_day(1_842_713.0).unwrap();
+    ///
+    /// assert_eq!(date.to_string(), "0333-01-27");
+    /// ```
+    pub fn from_julian_day(julian_day: f64) -> Result<Self, AnnualSolarEventError> {
+        let z = (julian_day + 0.5).floor() as i32;
+        let (year, month, day) = time_utils::calculate_date(z, Calendar::Julian)?;
+        Ok(JulianCalendarDate { year, month, day })
+    }
+
+    /// Converts a date in the proleptic Gregorian calendar to the Julian calendar.
+    ///
+    /// # Arguments
+    /// * `date` - The date in the proleptic Gregorian calendar.
+    ///
+    /// # Returns
+    /// The same day as a date in the Julian calendar.
+    ///
+    /// # Errors
+    /// Returns an error if the conversion fails due to invalid date components.
+    pub fn from_gregorian(date: NaiveDate) -> Result<Self, AnnualSolarEventError> {
+        let z = date.num_days_from_ce() + DAYS_FROM_CE_OFFSET;
+        let (year, month, day) = time_utils::calculate_date(z, Calendar::Julian)?;
+        Ok(JulianCalendarDate { year, month, day })
+    }
+
+    /// Converts the date to the proleptic Gregorian calendar.
+    ///
+    /// # Returns
+    /// The same day as a date in the proleptic Gregorian calendar.
+    ///
+    /// # Errors
+    /// Returns an error if the date is outside the range supported by chrono.
+    pub fn to_gregorian(&self) -> Result<NaiveDate, AnnualSolarEventError> {
+        let z = self.to_julian_day().ceil() as i32;
+        NaiveDate::from_num_days_from_ce_opt(z - DAYS_FROM_CE_OFFSET).ok_or(
+            AnnualSolarEventError::InvalidDateError(self.year, self.month, self.day),
+        )
+    }
+
+    /// Returns the Julian Day number at the start (midnight) of the date.
+    ///
+    /// # Example
+    /// ```
+    /// use solar_calendar_events::JulianCalendarDate;
+    ///
+    /// let date = JulianCalendarDate::from_ymd(1582, 10, 4).unwrap();
+    ///
+    /// assert_eq!(date.to_julian_day(), 2_299_159.5);
+    /// ```
+    pub fn to_julian_day(&self) -> f64 {
+        time_utils::calculate_julian_day(self.year, self.month, self.day, 0.0, Calendar::Julian)
+    }
+
+    /// Returns the year, using astronomical year numbering.
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    /// Returns the month (1-12).
+    pub fn month(&self) -> u32 {
+        self.month
+    }
+
+    /// Returns the day of the month.
+    pub fn day(&self) -> u32 {
+        self.day
+    }
+}
+
+impl fmt::Display for JulianCalendarDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if (0..=9_999).contains(&self.year) {
+            write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+        } else {
+            write!(f, "{:+05}-{:02}-{:02}", self.year, self.month, self.day)
+        }
+    }
+}
+
+/// A date in the calendar that was in use at the time: the Julian calendar before 1582-10-15 and
+/// the Gregorian calendar from then on.
+///
+/// chrono dates, including those from `from_julian_day` and the `date_time` of events, always use
+/// the proleptic Gregorian calendar, so dates before the reform differ from the dates in
+/// historical sources. `from_gregorian` converts them.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum HistoricalDate {
+    /// A date before 1582-10-15, in the Julian calendar.
+    Julian(JulianCalendarDate),
+
+    /// A date from 1582-10-15 on, in the Gregorian calendar.
+    Gregorian(NaiveDate),
+}
+
+impl HistoricalDate {
+    /// Converts a Julian Day number to the historical date of the day it falls on.
+    ///
+    /// # Arguments
+    /// * `julian_day` - The Julian Day number to convert.
+    ///
+    /// # Returns
+    /// The date in the calendar in use at the time.
+    ///
+    /// # Errors
+    /// Returns an error if the conversion fails due to invalid date components.
+    ///
+    /// # Example
+    /// ```
+    /// use solar_calendar_events::HistoricalDate;
+    ///
+    /// assert_eq!(HistoricalDate::from_julian_day(2_299_159.5).unwrap().to_string(), "1582-10-04");
+    /// assert_eq!(HistoricalDate::from_julian_day(2_299_160.5).unwrap().to_string(), "1582-10-15");
+    /// ```
+    pub fn from_julian_day(julian_day: f64) -> Result<Self, AnnualSolarEventError> {
+        let z = (julian_day + 0.5).floor() as i32;
+        if z < FIRST_GREGORIAN_JULIAN_DAY {
+            return JulianCalendarDate::from_julian_day(julian_day).map(HistoricalDate::Julian);
+        }
+        let (year, month, day) = time_utils::calculate_date(z, Calendar::Gregorian)?;
+        NaiveDate::from_ymd_opt(year, month, day)
+            .map(HistoricalDate::Gregorian)
+            .ok_or(AnnualSolarEventError::InvalidDateError(year, month, day))
+    }
+
+    /// Converts a date in the proleptic Gregorian calendar to the historical date of the same day.
+    ///
+    /// # Arguments
+    /// * `date` - The date in the proleptic Gregorian calendar.
+    ///
+    /// # Returns
+    /// The date in the calendar in use at the time.
+    ///
+    /// # Errors
+    /// Returns an error if the conversion fails due to invalid date components.
+    pub fn from_gregorian(date: NaiveDate) -> Result<Self, AnnualSolarEventError> {
+        if (date.year(), date.month(), date.day()) < GREGORIAN_REFORM {
+            JulianCalendarDate::from_gregorian(date).map(HistoricalDate::Julian)
+        } else {
+            Ok(HistoricalDate::Gregorian(date))
+        }
+    }
+
+    /// Returns the same day as a date in the proleptic Gregorian calendar.
+    ///
+    /// # Errors
+    /// Returns an error if the date is outside the range supported by chrono.
+    pub fn to_gregorian(&self) -> Result<NaiveDate, AnnualSolarEventError> {
+        match self {
+            HistoricalDate::Julian(date) => date.to_gregorian(),
+            HistoricalDate::Gregorian(date) => Ok(*date),
+        }
+    }
+
+    /// Returns the year, using astronomical year numbering.
+    pub fn year(&self) -> i32 {
+        match self {
+            HistoricalDate::Julian(date) => date.year(),
+            HistoricalDate::Gregorian(date) => date.year(),
+        }
+    }
+
+    /// Returns the month (1-12).
+    pub fn month(&self) -> u32 {
+        match self {
+            HistoricalDate::Julian(date) => date.month(),
+            HistoricalDate::Gregorian(date) => date.month(),
+        }
+    }
+
+    /// Returns the day of the month.
+    pub fn day(&self) -> u32 {
+        match self {
+            HistoricalDate::Julian(date) => date.day(),
+            HistoricalDate::Gregorian(date) => date.day(),
+        }
+    }
+}
+
+impl fmt::Display for HistoricalDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HistoricalDate::Julian(date) => date.fmt(f),
+            HistoricalDate::Gregorian(date) => date.fmt(f),
+        }
+    }
+}
@@ -0,0 +1,301 @@
+// Copyright (c) 2023-2024, Johan Thorén.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The cross-quarter days, when the apparent longitude of the Sun is midway between an equinox
+//! and a solstice: 315° (Imbolc), 45° (Beltane), 135° (Lughnasadh) and 225° (Samhain).
+//!
+//! Meeus gives no tables for these instants, so both calculation methods solve for the instant
+//! with VSOP87, starting from an estimate. The constants are linear polynomials for the instant
+//! when the mean longitude of the Sun (Meeus, equation 25.2) reaches the longitude of the event,
+//! with the mean tropical year as the factor.
+
+use chrono::{DateTime, Datelike, Utc};
+
+use crate::sealed::Sealed;
+use crate::{vsop87, AnnualSolarEvent, AnnualSolarEventError};
+
+/// The mean tropical year in days per millennium, the factor of the mean-Sun polynomials.
+const DAYS_PER_TROPICAL_MILLENNIUM: f64 = 365_242.189_6;
+
+/// Solves for the Julian Ephemeris Day of a cross-quarter day, starting from the instant given
+/// by the mean-Sun constants of the event.
+fn solve_cross_quarter<T: AnnualSolarEvent>(year: i32) -> f64 {
+    let (base, factor, _, _, _) = T::julian_day_constants();
+    let estimate = base + factor * (year as f64 - 2_000.0) / 1_000.0;
+    vsop87::solve_solar_longitude(T::solar_longitude(), estimate)
+}
+
+/// Represents the cross-quarter day between the December solstice and the March equinox, when the
+/// apparent longitude of the Sun is 315°, for a specific year.
+#[derive(Debug)]
+pub struct Imbolc {
+    julian_day: f64,
+    julian_ephemeris_day: f64,
+    date_time: DateTime<Utc>,
+}
+
+impl Sealed for Imbolc {
+    fn new(julian_day: f64, julian_ephemeris_day: f64, date_time: DateTime<Utc>) -> Self {
+        Self {
+            julian_day,
+            julian_ephemeris_day,
+            date_time,
+        }
+    }
+}
+
+impl AnnualSolarEvent for Imbolc {
+    fn date_time(&self) -> DateTime<Utc> {
+        self.date_time
+    }
+
+    fn julian_day(&self) -> f64 {
+        self.julian_day
+    }
+
+    fn julian_ephemeris_day(&self) -> f64 {
+        self.julian_ephemeris_day
+    }
+
+    fn year(&self) -> i32 {
+        self.date_time.year()
+    }
+
+    fn solar_longitude() -> f64 {
+        315.0
+    }
+
+    fn julian_day_constants() -> (f64, f64, f64, f64, f64) {
+        (
+            2_451_580.036_40,
+            DAYS_PER_TROPICAL_MILLENNIUM,
+            0.0,
+            0.0,
+            0.0,
+        )
+    }
+
+    fn calculate_julian_day(year: i32) -> f64 {
+        solve_cross_quarter::<Self>(year)
+    }
+}
+
+/// Represents the cross-quarter day between the March equinox and the June solstice, when the
+/// apparent longitude of the Sun is 45°, for a specific year.
+#[derive(Debug)]
+pub struct Beltane {
+    julian_day: f64,
+    julian_ephemeris_day: f64,
+    date_time: DateTime<Utc>,
+}
+
+impl Sealed for Beltane {
+    fn new(julian_day: f64, julian_ephemeris_day: f64, date_time: DateTime<Utc>) -> Self {
+        Self {
+            julian_day,
+            julian_ephemeris_day,
+            date_time,
+        }
+    }
+}
+
+impl AnnualSolarEvent for Beltane {
+    fn date_time(&self) -> DateTime<Utc> {
+        self.date_time
+    }
+
+    fn julian_day(&self) -> f64 {
+        self.julian_day
+    }
+
+    fn julian_ephemeris_day(&self) -> f64 {
+        self.julian_ephemeris_day
+    }
+
+    fn year(&self) -> i32 {
+        self.date_time.year()
+    }
+
+    fn solar_longitude() -> f64 {
+        45.0
+    }
+
+    fn julian_day_constants() -> (f64, f64, f64, f64, f64) {
+        (
+            2_451_671.346_95,
+            DAYS_PER_TROPICAL_MILLENNIUM,
+            0.0,
+            0.0,
+            0.0,
+        )
+    }
+
+    fn calculate_julian_day(year: i32) -> f64 {
+        solve_cross_quarter::<Self>(year)
+    }
+}
+
+/// Represents the cross-quarter day between the June solstice and the September equinox, when the
+/// apparent longitude of the Sun is 135°, for a specific year.
+#[derive(Debug)]
+pub struct Lughnasadh {
+    julian_day: f64,
+    julian_ephemeris_day: f64,
+    date_time: DateTime<Utc>,
+}
+
+impl Sealed for Lughnasadh {
+    fn new(julian_day: f64, julian_ephemeris_day: f64, date_time: DateTime<Utc>) -> Self {
+        Self {
+            julian_day,
+            julian_ephemeris_day,
+            date_time,
+        }
+    }
+}
+
+impl AnnualSolarEvent for Lughnasadh {
+    fn date_time(&self) -> DateTime<Utc> {
+        self.date_time
+    }
+
+    fn julian_day(&self) -> f64 {
+        self.julian_day
+    }
+
+    fn julian_ephemeris_day(&self) -> f64 {
+        self.julian_ephemeris_day
+    }
+
+    fn year(&self) -> i32 {
+        self.date_time.year()
+    }
+
+    fn solar_longitude() -> f64 {
+        135.0
+    }
+
+    fn julian_day_constants() -> (f64, f64, f64, f64, f64) {
+        (
+            2_451_762.657_50,
+            DAYS_PER_TROPICAL_MILLENNIUM,
+            0.0,
+            0.0,
+            0.0,
+        )
+    }
+
+    fn calculate_julian_day(year: i32) -> f64 {
+        solve_cross_quarter::<Self>(year)
+    }
+}
+
+/// Represents the cross-quarter day between the September equinox and the December solstice, when
+/// the apparent longitude of the Sun is 225°, for a specific year.
+#[derive(Debug)]
+pub struct Samhain {
+    julian_day: f64,
+    julian_ephemeris_day: f64,
+    date_time: DateTime<Utc>,
+}
+
+impl Sealed for Samhain {
+    fn new(julian_day: f64, julian_ephemeris_day: f64, date_time: DateTime<Utc>) -> Self {
+        Self {
+            julian_day,
+            julian_ephemeris_day,
+            date_time,
+        }
+    }
+}
+
+impl AnnualSolarEvent for Samhain {
+    fn date_time(&self) -> DateTime<Utc> {
+        self.date_time
+    }
+
+    fn julian_day(&self) -> f64 {
+        self.julian_day
+    }
+
+    fn julian_ephemeris_day(&self) -> f64 {
+        self.julian_ephemeris_day
+    }
+
+    fn year(&self) -> i32 {
+        self.date_time.year()
+    }
+
+    fn solar_longitude() -> f64 {
+        225.0
+    }
+
+    fn julian_day_constants() -> (f64, f64, f64, f64, f64) {
+        (
+            2_451_853.968_05,
+            DAYS_PER_TROPICAL_MILLENNIUM,
+            0.0,
+            0.0,
+            0.0,
+        )
+    }
+
+    fn calculate_julian_day(year: i32) -> f64 {
+        solve_cross_quarter::<Self>(year)
+    }
+}
+
+/// Contains all four cross-quarter days (Imbolc, Beltane, Lughnasadh and Samhain) for a given
+/// year.
+#[derive(Debug)]
+pub struct AnnualCrossQuarterEvents {
+    imbolc: Imbolc,
+    beltane: Beltane,
+    lughnasadh: Lughnasadh,
+    samhain: Samhain,
+}
+
+impl AnnualCrossQuarterEvents {
+    /// Creates a new `AnnualCrossQuarterEvents` instance for the specified year, which contains
+    /// all four cross-quarter days.
+    ///
+    /// Returns an error if the year is outside the valid range.
+    pub fn for_year(year: i32) -> Result<Self, AnnualSolarEventError> {
+        Ok(Self {
+            imbolc: Imbolc::for_year(year)?,
+            beltane: Beltane::for_year(year)?,
+            lughnasadh: Lughnasadh::for_year(year)?,
+            samhain: Samhain::for_year(year)?,
+        })
+    }
+
+    /// Returns a reference to the Imbolc event.
+    pub fn imbolc(&self) -> &Imbolc {
+        &self.imbolc
+    }
+
+    /// Returns a reference to the Beltane event.
+    pub fn beltane(&self) -> &Beltane {
+        &self.beltane
+    }
+
+    /// Returns a reference to the Lughnasadh event.
+    pub fn lughnasadh(&self) -> &Lughnasadh {
+        &self.lughnasadh
+    }
+
+    /// Returns a reference to the Samhain event.
+    pub fn samhain(&self) -> &Samhain {
+        &self.samhain
+    }
+
+    /// Returns the year of these cross-quarter days.
+    pub fn year(&self) -> i32 {
+        self.imbolc.year()
+    }
+}
@@ -0,0 +1,159 @@
+// Copyright (c) 2023-2024, Johan Thorén.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The difference ΔT = TT − UT between Terrestrial (Dynamical) Time and Universal Time, using
+//! the polynomial expressions by Espenak and Meeus from the NASA *Five Millennium Canon of Solar
+//! Eclipses*.
+
+use crate::meeus::J2000;
+
+/// The mean length of a Gregorian year, in days.
+const DAYS_PER_GREGORIAN_YEAR: f64 = 365.242_5;
+
+/// Number of seconds in a day.
+const SECONDS_PER_DAY: f64 = 86_400.0;
+
+/// Evaluates a polynomial with the given coefficients, in ascending order of power.
+fn polynomial(coefficients: &[f64], x: f64) -> f64 {
+    coefficients.iter().rev().fold(0.0, |acc, c| acc * x + c)
+}
+
+/// Calculates ΔT for a given decimal year.
+///
+/// # Arguments
+/// * `year` - The decimal year, using astronomical year numbering (e.g. 2024.5 for the middle of
+///   2024).
+///
+/// # Returns
+/// ΔT = TT − UT, in seconds.
+///
+/// # Example
+/// ```
+/// use solar_calendar_events::delta_t;
+///
+/// assert!((delta_t(2000.0) - 63.86).abs() < 0.01);
+/// ```
+pub fn delta_t(year: f64) -> f64 {
+    let y = year;
+    match y {
+        _ if y < -500.0 => long_term(y),
+        _ if y < 500.0 => polynomial(
+            &[
+                10_583.6,
+                -1_014.41,
+                33.783_11,
+                -5.952_053,
+                -0.179_845_2,
+                0.022_174_192,
+                0.009_031_652_1,
+            ],
+            y / 100.0,
+        ),
+        _ if y < 1_600.0 => polynomial(
+            &[
+                1_574.2,
+                -556.01,
+                71.234_72,
+                0.319_781,
+                -0.850_346_3,
+                -0.005_050_998,
+                0.008_357_207_3,
+            ],
+            (y - 1_000.0) / 100.0,
+        ),
+        _ if y < 1_700.0 => polynomial(&[120.0, -0.980_8, -0.015_32, 1.0 / 7_129.0], y - 1_600.0),
+        _ if y < 1_800.0 => polynomial(
+            &[
+                8.83,
+                0.160_3,
+                -0.005_928_5,
+                0.000_133_36,
+                -1.0 / 1_174_000.0,
+            ],
+            y - 1_700.0,
+        ),
+        _ if y < 1_860.0 => polynomial(
+            &[
+                13.72,
+                -0.332_447,
+                0.006_861_2,
+                0.004_111_6,
+                -0.000_374_36,
+                0.000_012_127_2,
+                -0.000_000_169_9,
+                0.000_000_000_875,
+            ],
+            y - 1_800.0,
+        ),
+        _ if y < 1_900.0 => polynomial(
+            &[
+                7.62,
+                0.573_7,
+                -0.251_754,
+                0.016_806_68,
+                -0.000_447_362_4,
+                1.0 / 233_174.0,
+            ],
+            y - 1_860.0,
+        ),
+        _ if y < 1_920.0 => polynomial(
+            &[-2.79, 1.494_119, -0.059_893_9, 0.006_196_6, -0.000_197],
+            y - 1_900.0,
+        ),
+        _ if y < 1_941.0 => polynomial(&[21.20, 0.844_93, -0.076_100, 0.002_093_6], y - 1_920.0),
+        _ if y < 1_961.0 => polynomial(&[29.07, 0.407, -1.0 / 233.0, 1.0 / 2_547.0], y - 1_950.0),
+        _ if y < 1_986.0 => polynomial(&[45.45, 1.067, -1.0 / 260.0, -1.0 / 718.0], y - 1_975.0),
+        _ if y < 2_005.0 => polynomial(
+            &[
+                63.86,
+                0.334_5,
+                -0.060_374,
+                0.001_727_5,
+                0.000_651_814,
+                0.000_023_735_99,
+            ],
+            y - 2_000.0,
+        ),
+        _ if y < 2_050.0 => polynomial(&[62.92, 0.322_17, 0.005_589], y - 2_000.0),
+        _ if y < 2_150.0 => long_term(y) - 0.562_8 * (2_150.0 - y),
+        _ => long_term(y),
+    }
+}
+
+/// The long-term parabola used outside the range of the fitted polynomials.
+fn long_term(year: f64) -> f64 {
+    let u = (year - 1_820.0) / 100.0;
+    -20.0 + 32.0 * u * u
+}
+
+/// Calculates the decimal year of a Julian Day, precise enough for looking up ΔT.
+fn decimal_year(julian_day: f64) -> f64 {
+    2_000.0 + (julian_day - J2000) / DAYS_PER_GREGORIAN_YEAR
+}
+
+/// Converts a Julian Ephemeris Day (Terrestrial Time) to a Julian Day in Universal Time.
+///
+/// # Arguments
+/// * `julian_ephemeris_day` - The Julian Ephemeris Day to convert.
+///
+/// # Returns
+/// The Julian Day in Universal Time.
+pub fn universal_julian_day(julian_ephemeris_day: f64) -> f64 {
+    julian_ephemeris_day - delta_t(decimal_year(julian_ephemeris_day)) / SECONDS_PER_DAY
+}
+
+/// Converts a Julian Day in Universal Time to a Julian Ephemeris Day (Terrestrial Time).
+///
+/// # Arguments
+/// * `julian_day` - The Julian Day in Universal Time to convert.
+///
+/// # Returns
+/// The Julian Ephemeris Day.
+pub fn julian_ephemeris_day(julian_day: f64) -> f64 {
+    julian_day + delta_t(decimal_year(julian_day)) / SECONDS_PER_DAY
+}
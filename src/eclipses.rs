@@ -0,0 +1,332 @@
+// Copyright (c) 2023-2024, Johan Thorén.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Solar and lunar eclipses, following Meeus, *Astronomical Algorithms* (2nd ed.), chapter 54.
+
+use std::slice;
+
+use chrono::{DateTime, Datelike, Utc};
+
+use crate::lunar_phases::{lunation_before, start_of_year, LunationArguments};
+use crate::{delta_t, meeus, AnnualSolarEventError, JulianDateTimeUtc};
+
+/// The limit of `|sin F|`, the argument of latitude of the Moon, beyond which the Moon is too far
+/// from a node for an eclipse.
+const MAX_SIN_ARGUMENT_OF_LATITUDE: f64 = 0.36;
+
+/// Whether the Sun or the Moon is eclipsed.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum EclipseKind {
+    /// The Moon hides the Sun, at new Moon.
+    Solar,
+    /// The Earth's shadow falls on the Moon, at full Moon.
+    Lunar,
+}
+
+/// The type of an eclipse.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum EclipseType {
+    /// The Sun is completely hidden by the Moon, or the Moon is completely inside the umbra of the
+    /// Earth.
+    Total,
+    /// The Moon is too small to hide the Sun, leaving a ring of sunlight. Solar eclipses only.
+    Annular,
+    /// A solar eclipse that is total along part of its path and annular along the rest.
+    Hybrid,
+    /// The Sun is partly hidden by the Moon, or the Moon is partly inside the umbra of the Earth.
+    Partial,
+    /// The Moon passes through the penumbra of the Earth, but misses the umbra. Lunar eclipses
+    /// only.
+    Penumbral,
+}
+
+/// A solar or lunar eclipse at the instant of greatest eclipse.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Eclipse {
+    kind: EclipseKind,
+    eclipse_type: EclipseType,
+    julian_day: f64,
+    julian_ephemeris_day: f64,
+    date_time: DateTime<Utc>,
+    magnitude: f64,
+    gamma: f64,
+}
+
+impl Eclipse {
+    /// Calculates the eclipse at the new Moon (`EclipseKind::Solar`) or full Moon
+    /// (`EclipseKind::Lunar`) of lunation `lunation`, counted from the new Moon of 2000 January 6.
+    ///
+    /// Returns `None` if there is no eclipse.
+    fn calculate(kind: EclipseKind, lunation: i64) -> Option<(f64, EclipseType, f64, f64)> {
+        let k = lunation as f64
+            + match kind {
+                EclipseKind::Solar => 0.0,
+                EclipseKind::Lunar => 0.5,
+            };
+        let LunationArguments {
+            t,
+            jde,
+            e,
+            m,
+            m_moon,
+            f,
+            omega,
+        } = LunationArguments::new(k);
+        if f.sin().abs() > MAX_SIN_ARGUMENT_OF_LATITUDE {
+            return None;
+        }
+        let f1 = f - 0.026_65_f64.to_radians() * omega.sin();
+        let a1 = (299.77 + 0.107_408 * k - 0.009_173 * t * t).to_radians();
+
+        let (c1, c2) = match kind {
+            EclipseKind::Solar => (-0.407_5, 0.172_1),
+            EclipseKind::Lunar => (-0.406_5, 0.172_7),
+        };
+        let julian_ephemeris_day =
+            jde + c1 * m_moon.sin() + c2 * e * m.sin() + 0.016_1 * (2.0 * m_moon).sin()
+                - 0.009_7 * (2.0 * f1).sin()
+                + 0.007_3 * e * (m_moon - m).sin()
+                - 0.005_0 * e * (m_moon + m).sin()
+                - 0.002_3 * (m_moon - 2.0 * f1).sin()
+                + 0.002_1 * e * (2.0 * m).sin()
+                + 0.001_2 * (m_moon + 2.0 * f1).sin()
+                + 0.000_6 * e * (2.0 * m_moon + m).sin()
+                - 0.000_4 * (3.0 * m_moon).sin()
+                - 0.000_3 * e * (m + 2.0 * f1).sin()
+                + 0.000_3 * a1.sin()
+                - 0.000_2 * e * (m - 2.0 * f1).sin()
+                - 0.000_2 * e * (2.0 * m_moon - m).sin()
+                - 0.000_2 * omega.sin();
+
+        // The least distance from the axis of the shadow to the center of the Earth (`gamma`) and
+        // the radius of the umbral cone in the fundamental plane (`u`), both in equatorial radii
+        // of the Earth.
+        let p = 0.207_0 * e * m.sin() + 0.002_4 * e * (2.0 * m).sin() - 0.039_2 * m_moon.sin()
+            + 0.011_6 * (2.0 * m_moon).sin()
+            - 0.007_3 * e * (m_moon + m).sin()
+            + 0.006_7 * e * (m_moon - m).sin()
+            + 0.011_8 * (2.0 * f1).sin();
+        let q = 5.220_7 - 0.004_8 * e * m.cos() + 0.002_0 * e * (2.0 * m).cos()
+            - 0.329_9 * m_moon.cos()
+            - 0.006_0 * e * (m_moon + m).cos()
+            + 0.004_1 * e * (m_moon - m).cos();
+        let w = f1.cos().abs();
+        let gamma = (p * f1.cos() + q * f1.sin()) * (1.0 - 0.004_8 * w);
+        let u = 0.005_9 + 0.004_6 * e * m.cos() - 0.018_2 * m_moon.cos()
+            + 0.000_4 * (2.0 * m_moon).cos()
+            - 0.000_5 * (m + m_moon).cos();
+
+        let (eclipse_type, magnitude) = match kind {
+            EclipseKind::Solar => solar_eclipse(gamma, u)?,
+            EclipseKind::Lunar => lunar_eclipse(gamma, u)?,
+        };
+        Some((julian_ephemeris_day, eclipse_type, magnitude, gamma))
+    }
+
+    /// Returns whether the eclipse is solar or lunar.
+    pub fn kind(&self) -> EclipseKind {
+        self.kind
+    }
+
+    /// Returns the type of the eclipse.
+    pub fn eclipse_type(&self) -> EclipseType {
+        self.eclipse_type
+    }
+
+    /// Returns the date and time of greatest eclipse as a `DateTime<Utc>`.
+    pub fn date_time(&self) -> DateTime<Utc> {
+        self.date_time
+    }
+
+    /// Returns the Julian Day Number of greatest eclipse in Universal Time.
+    pub fn julian_day(&self) -> f64 {
+        self.julian_day
+    }
+
+    /// Returns the Julian Ephemeris Day of greatest eclipse, i.e. the Julian Day in Terrestrial
+    /// (Dynamical) Time.
+    pub fn julian_ephemeris_day(&self) -> f64 {
+        self.julian_ephemeris_day
+    }
+
+    /// Returns the magnitude of the eclipse at greatest eclipse.
+    ///
+    /// For a solar eclipse, this is the fraction of the diameter of the Sun covered by the Moon,
+    /// or for a total or annular eclipse the ratio of their apparent diameters. For a lunar
+    /// eclipse, this is the fraction of the diameter of the Moon inside the umbra of the Earth,
+    /// or inside the penumbra for a penumbral eclipse.
+    pub fn magnitude(&self) -> f64 {
+        self.magnitude
+    }
+
+    /// Returns gamma, the least distance from the axis of the shadow (the umbra of the Moon for a
+    /// solar eclipse, of the Earth for a lunar eclipse) to the center of the Earth or the Moon,
+    /// in equatorial radii of the Earth. It is positive when the axis passes north of the center.
+    pub fn gamma(&self) -> f64 {
+        self.gamma
+    }
+}
+
+/// Classifies a solar eclipse and calculates its magnitude from `gamma` and the radius `u` of the
+/// umbral cone in the fundamental plane. Returns `None` if there is no eclipse.
+fn solar_eclipse(gamma: f64, u: f64) -> Option<(EclipseType, f64)> {
+    let distance = gamma.abs();
+    if distance > 1.543_3 + u {
+        return None;
+    }
+    if distance > 0.997_2 + u.abs() {
+        return Some((
+            EclipseType::Partial,
+            (1.543_3 + u - distance) / (0.546_1 + 2.0 * u),
+        ));
+    }
+
+    // The umbral cone narrows by about 0.004 64 radii from the fundamental plane to the surface
+    // of the Earth below the axis of the shadow. A non-central eclipse (`distance` above 0.997 2)
+    // touches the surface at the limb of the Earth, where the correction vanishes.
+    let omega = 0.004_64 * (1.0 - gamma * gamma).max(0.0).sqrt();
+    let eclipse_type = if u < 0.0 {
+        EclipseType::Total
+    } else if u > 0.004_7 || u >= omega {
+        EclipseType::Annular
+    } else {
+        EclipseType::Hybrid
+    };
+    // The ratio of the apparent diameters of the Moon and the Sun, from the radii of the
+    // penumbral and umbral cones at the surface.
+    Some((eclipse_type, (0.546_1 + 2.0 * omega) / (0.546_1 + 2.0 * u)))
+}
+
+/// Classifies a lunar eclipse and calculates its magnitude from `gamma` and the radius `u` of the
+/// umbral cone of the Moon. Returns `None` if there is no eclipse.
+fn lunar_eclipse(gamma: f64, u: f64) -> Option<(EclipseType, f64)> {
+    let distance = gamma.abs();
+    let umbral_magnitude = (1.012_8 - u - distance) / 0.545_0;
+    let penumbral_magnitude = (1.557_3 + u - distance) / 0.545_0;
+
+    if umbral_magnitude >= 1.0 {
+        Some((EclipseType::Total, umbral_magnitude))
+    } else if umbral_magnitude > 0.0 {
+        Some((EclipseType::Partial, umbral_magnitude))
+    } else if penumbral_magnitude > 0.0 {
+        Some((EclipseType::Penumbral, penumbral_magnitude))
+    } else {
+        None
+    }
+}
+
+/// Contains the solar and lunar eclipses within a year or a range of instants, in chronological
+/// order.
+///
+/// The instants of greatest eclipse are accurate to a few minutes, and the magnitudes and gamma to
+/// about 0.01.
+#[derive(Debug)]
+pub struct Eclipses {
+    events: Vec<Eclipse>,
+}
+
+impl Eclipses {
+    /// Creates a new `Eclipses` instance with the eclipses in the specified year.
+    ///
+    /// Returns an error if the year is outside the valid range.
+    ///
+    /// # Example
+    /// ```
+    /// use solar_calendar_events::{EclipseKind, EclipseType, Eclipses};
+    ///
+    /// let eclipses = Eclipses::for_year(2024).unwrap();
+    /// let total = eclipses
+    ///     .iter()
+    ///     .find(|eclipse| eclipse.eclipse_type() == EclipseType::Total)
+    ///     .unwrap();
+    ///
+    /// assert_eq!(total.kind(), EclipseKind::Solar);
+    /// assert_eq!(total.date_time().format("%F").to_string(), "2024-04-08");
+    /// ```
+    pub fn for_year(year: i32) -> Result<Self, AnnualSolarEventError> {
+        if !meeus::YEAR_RANGE.contains(&year) {
+            return Err(AnnualSolarEventError::YearOutOfRange(year));
+        }
+        Self::calculate(start_of_year(year)?, start_of_year(year + 1)?)
+    }
+
+    /// Creates a new `Eclipses` instance with the eclipses whose greatest eclipse is from `start`
+    /// (inclusive) to `end` (exclusive). The result is empty if `end` is not after `start`.
+    ///
+    /// Returns an error if either instant is in a year outside the valid range.
+    pub fn between(
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<Self, AnnualSolarEventError> {
+        for year in [start.year(), end.year()] {
+            if !meeus::YEAR_RANGE.contains(&year) {
+                return Err(AnnualSolarEventError::YearOutOfRange(year));
+            }
+        }
+        Self::calculate(start.to_julian_day(), end.to_julian_day())
+    }
+
+    /// Calculates the eclipses from Julian Day `start` (inclusive) to `end` (exclusive), both in
+    /// Universal Time.
+    fn calculate(start: f64, end: f64) -> Result<Self, AnnualSolarEventError> {
+        let mut lunation = lunation_before(start);
+        let mut events = Vec::new();
+        loop {
+            for kind in [EclipseKind::Solar, EclipseKind::Lunar] {
+                let Some((julian_ephemeris_day, eclipse_type, magnitude, gamma)) =
+                    Eclipse::calculate(kind, lunation)
+                else {
+                    continue;
+                };
+                let julian_day = delta_t::universal_julian_day(julian_ephemeris_day);
+                if julian_day >= end {
+                    return Ok(Self { events });
+                }
+                if julian_day >= start {
+                    events.push(Eclipse {
+                        kind,
+                        eclipse_type,
+                        julian_day,
+                        julian_ephemeris_day,
+                        date_time: DateTime::<Utc>::from_julian_day(julian_day)?,
+                        magnitude,
+                        gamma,
+                    });
+                }
+            }
+            lunation += 1;
+        }
+    }
+
+    /// Returns an iterator over the eclipses in chronological order.
+    pub fn iter(&self) -> EclipsesIter<'_> {
+        self.events.iter()
+    }
+
+    /// Returns the number of eclipses.
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+
+    /// Returns `true` if there are no eclipses.
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+}
+
+/// An iterator over eclipses in chronological order.
+pub type EclipsesIter<'a> = slice::Iter<'a, Eclipse>;
+
+impl<'a> IntoIterator for &'a Eclipses {
+    type Item = &'a Eclipse;
+    type IntoIter = EclipsesIter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
@@ -5,16 +5,72 @@
 // <LICENSE or http://opensource.org/licenses/MIT>, at your
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
-use chrono::{DateTime, Datelike, NaiveDate, NaiveDateTime, NaiveTime, TimeDelta, Utc};
+use std::ops::RangeInclusive;
+
+use chrono::{DateTime, Datelike, NaiveDate, NaiveDateTime, NaiveTime, TimeDelta, Timelike, Utc};
 use thiserror::Error;
+use time_utils::Calendar;
+
+pub use apsides::{Aphelion, Perihelion};
+pub use calendar::{HistoricalDate, JulianCalendarDate};
+pub use cross_quarter::{AnnualCrossQuarterEvents, Beltane, Imbolc, Lughnasadh, Samhain};
+pub use delta_t::delta_t;
+pub use eclipses::{Eclipse, EclipseKind, EclipseType, Eclipses, EclipsesIter};
+pub use lunar_phases::{LunarPhase, LunarPhaseEvent, LunarPhases, LunarPhasesIter};
+pub use observer::{Observer, RiseSet, SolarDay, Twilight};
+pub use polar::{PolarPeriod, PolarPeriodKind, PolarPeriods};
+pub use season::{Hemisphere, Season, SeasonKind, SeasonalEvent};
+pub use solar_event::{
+    next_solar_event_after, previous_solar_event_before, SolarEvent, SolarEventIter,
+    SolarEventKind, SolarEventsIter,
+};
+pub use solar_terms::{SolarTerm, SolarTermEvent, SolarTerms, SolarTermsIter};
+pub use solar_time::{
+    equation_of_time, local_apparent_time, local_mean_time, utc_from_local_apparent_time,
+    utc_from_local_mean_time,
+};
+pub use sun_position::SunPosition;
+pub use time_scale::{LeapSecondTable, TimeScale};
+pub use zodiac::{Ayanamsa, Zodiac, ZodiacIngress, ZodiacIngresses, ZodiacSign};
+
+mod apsides;
+mod calendar;
+mod cross_quarter;
+mod delta_t;
+mod eclipses;
+mod lunar_phases;
+mod meeus;
+mod nutation;
+mod observer;
+mod polar;
+mod season;
+mod solar_event;
+mod solar_terms;
+mod solar_time;
+mod sun_position;
+mod time_scale;
+mod vsop87;
+mod zodiac;
 
 /// Represents errors that can occur when calculating the date and time of an annual solar event.
+///
+/// New variants may be added in minor releases, so matches on this enum need a wildcard arm.
 #[derive(Error, Debug, Eq, PartialEq)]
+#[non_exhaustive]
 pub enum AnnualSolarEventError {
     /// Error when unable to create a valid date with the given year, month, and day.
     #[error("Unable to set the date: {0}")]
     InvalidDateError(i32, u32, u32),
 
+    /// Error when a leap second file cannot be read.
+    #[error("Unable to read leap second file: {0}")]
+    LeapSecondFileError(String),
+
+    /// Error when a line of a leap second file cannot be parsed, or when the file holds no
+    /// entries (line 0).
+    #[error("Invalid leap second file on line {0}")]
+    LeapSecondParseError(usize),
+
     /// Error for an invalid month number.
     #[error("Invalid month number: {0}")]
     MonthOutOfRange(i32),
@@ -24,18 +80,61 @@ pub enum AnnualSolarEventError {
     #[error("Unable to create NaiveTime object from hour {0}, minute {1}, second {2}")]
     NaiveTimeError(u32, u32, u32),
 
-    /// Error for failing to parse a floating-point number.
-    #[error("Unable to parse float: {0}")]
-    ParseFloatError(#[from] std::num::ParseFloatError),
+    /// Error when an event does not occur in the specified year, as happens for the perihelion in
+    /// some years around 1800.
+    #[error("No event in year: {0}")]
+    NoEventInYear(i32),
 
-    /// Error when the specified year is out of range (1900–2100).
-    #[error("Year out of range: {0}, must be between 1900 and 2100")]
+    /// Error when a string is not the name of a kind of solar event.
+    #[error("Unknown solar event: {0}")]
+    UnknownSolarEventKind(String),
+
+    /// Error when the specified year is outside the range supported by the algorithm in use.
+    #[error("Year out of range: {0}")]
     YearOutOfRange(i32),
 }
 
+/// The method used to calculate the instant of an annual solar event.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum CalculationMethod {
+    /// The mean-event polynomials and periodic terms from Meeus, *Astronomical Algorithms*,
+    /// chapter 27. Fast and accurate to about a minute.
+    #[default]
+    Meeus,
+
+    /// Solves for the instant when the apparent geocentric longitude of the Sun, calculated from
+    /// a truncated VSOP87 theory with nutation and aberration, reaches the longitude of the event.
+    /// Slower, but supports a wider range of years.
+    ///
+    /// The series from Meeus, Appendix III, leave out the smaller terms of VSOP87, so this is not
+    /// the sub-second accuracy of the full theory. The instants are within a few seconds of those
+    /// from the full theory, such as the June solstice of 1962 in Meeus, example 27.a, and round to
+    /// the minutes published by the U.S. Naval Observatory.
+    TruncatedVsop87,
+}
+
+impl CalculationMethod {
+    /// Returns the range of years supported by the calculation method.
+    ///
+    /// # Returns
+    /// The inclusive range of supported years, using astronomical year numbering.
+    pub fn year_range(&self) -> RangeInclusive<i32> {
+        match self {
+            CalculationMethod::Meeus => meeus::YEAR_RANGE,
+            CalculationMethod::TruncatedVsop87 => vsop87::YEAR_RANGE,
+        }
+    }
+}
+
 /// Utility functions for internal calculations related to annual solar events.
 mod time_utils {
-    use super::{AnnualSolarEventError, JulianDayNumber};
+    use super::AnnualSolarEventError;
+
+    /// Number of nanoseconds in a second.
+    const NANOSECONDS_PER_SECOND: u64 = 1_000_000_000;
+
+    /// Number of nanoseconds in a day.
+    const NANOSECONDS_PER_DAY: u64 = 86_400 * NANOSECONDS_PER_SECOND;
 
     /// Calculates the month and year based on intermediate values from Julian Day calculations.
     ///
@@ -58,63 +157,117 @@ mod time_utils {
         Ok((month, year))
     }
 
-    /// Calculates the day and fraction of the day from intermediate values in Julian Day
-    /// calculations.
+    /// The calendar in which a date is expressed.
+    #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+    pub enum Calendar {
+        /// The Julian calendar, with a leap day every fourth year.
+        Julian,
+        /// The proleptic Gregorian calendar, as used by chrono.
+        Gregorian,
+    }
+
+    /// Calculates the Julian Day of a calendar date and time of day.
+    ///
+    /// # Arguments
+    /// * `year` - The year, using astronomical year numbering.
+    /// * `month` - The month (1-12).
+    /// * `day` - The day of the month.
+    /// * `fraction_of_day` - The fraction of the day elapsed since midnight.
+    /// * `calendar` - The calendar in which the date is expressed.
+    ///
+    /// # Returns
+    /// The Julian Day as a floating-point number.
+    pub fn calculate_julian_day(
+        year: i32,
+        month: u32,
+        day: u32,
+        fraction_of_day: f64,
+        calendar: Calendar,
+    ) -> f64 {
+        let (y, m) = if month > 2 {
+            (year as f64, month as f64)
+        } else {
+            (year as f64 - 1.0, month as f64 + 12.0)
+        };
+        let b = match calendar {
+            Calendar::Julian => 0.0,
+            Calendar::Gregorian => {
+                let a = (y / 100.0).floor();
+                2.0 - a + (a / 4.0).floor()
+            }
+        };
+
+        (365.25 * (y + 4_716.0)).floor() + (30.600_1 * (m + 1.0)).floor() + day as f64 + b - 1_524.5
+            + fraction_of_day
+    }
+
+    /// Calculates the calendar date of the day starting at midnight before a Julian Day number.
+    ///
+    /// # Arguments
+    /// * `z` - The integer part of the Julian Day plus 0.5.
+    /// * `calendar` - The calendar in which to express the date.
+    ///
+    /// # Returns
+    /// A tuple containing the year (i32), month (u32) and day (u32).
+    ///
+    /// # Errors
+    /// Returns an error if the month is out of range (1-12).
+    pub fn calculate_date(
+        z: i32,
+        calendar: Calendar,
+    ) -> Result<(i32, u32, u32), AnnualSolarEventError> {
+        let a: i32 = match calendar {
+            Calendar::Julian => z,
+            Calendar::Gregorian => {
+                let alpha: i32 = ((z as f64 - 1_867_216.25) / 36_524.25).floor() as i32;
+                z + 1 + alpha - (alpha as f64 / 4.0).floor() as i32
+            }
+        };
+        let b: i32 = a + 1_524;
+        let c: i32 = ((b as f64 - 122.1) / 365.25) as i32;
+        let d: i32 = (365.25 * c as f64) as i32;
+        let e: i32 = ((b - d) as f64 / 30.600_1) as i32;
+        let (month, year) = calculate_month_and_year(e, c)?;
+        Ok((year, month, calculate_day(b, d, e)))
+    }
+
+    /// Calculates the day of the month from intermediate values in Julian Day calculations.
     ///
     /// # Arguments
-    /// * `f` - The fractional part of the Julian Day.
     /// * `b` - The intermediate value `b` from Julian Day calculations.
     /// * `d` - The intermediate value `d` from Julian Day calculations.
     /// * `e` - The intermediate value `e` from Julian Day calculations.
     ///
     /// # Returns
-    /// A tuple containing the day (u32) and the fractional part of the day (f64).
-    pub fn calculate_day(f: f64, b: i32, d: i32, e: i32) -> (u32, f64) {
-        let day_with_decimal: f64 =
-            f + (b as f64 - d as f64 - ((e as f64 * 30.600_1) as i32 as f64));
-        let day: u32 = day_with_decimal as u32;
-        let fraction_of_day: f64 = day_with_decimal - day as f64;
-        (day, fraction_of_day)
+    /// The day of the month (u32).
+    pub fn calculate_day(b: i32, d: i32, e: i32) -> u32 {
+        (b - d - (e as f64 * 30.600_1) as i32) as u32
     }
 
-    /// Calculates the hour, minute, second, and determines if the day should move forward based
-    /// on the fractional day.
+    /// Calculates the hour, minute, second and nanosecond, and determines if the day should move
+    /// forward based on the fractional day.
+    ///
+    /// The fraction is rounded to the nearest nanosecond, with halfway cases rounded away from
+    /// zero. A fraction that rounds up to a whole day moves the day forward.
     ///
     /// # Arguments
-    /// * `fraction_of_day` - The fractional part of the day.
+    /// * `fraction_of_day` - The fractional part of the day, in the range `0.0..1.0`.
     ///
     /// # Returns
-    /// A tuple containing the hour (u32), minute (u32), second (u32), and a boolean indicating if
-    /// the day should move forward.
-    ///
-    /// # Errors
-    /// Returns an error if the rounding or parsing fails.
-    pub fn calculate_hour_minute_second(
-        fraction_of_day: f64,
-    ) -> Result<(u32, u32, u32, bool), AnnualSolarEventError> {
-        let hour_with_decimal: f64 = 24.0 * fraction_of_day;
-        let mut hour: u32 = hour_with_decimal as u32;
-        let fraction_of_hour: f64 = (hour_with_decimal - hour as f64).to_five_decimals()?;
-        let minute_with_decimal: f64 = 60.0 * fraction_of_hour;
-        let mut minute: u32 = minute_with_decimal as u32;
-        let fraction_of_minute: f64 = 0.01 + minute_with_decimal - minute as f64;
-        let mut second: u32 = (60.0 * fraction_of_minute) as u32;
-        let mut move_day_forward = false;
-
-        if second == 60 {
-            minute += 1;
-            second = 0;
-        }
-        if minute == 60 {
-            hour += 1;
-            minute = 0;
-        }
-        if hour == 24 {
-            hour = 0;
-            move_day_forward = true;
-        }
+    /// A tuple containing the hour (u32), minute (u32), second (u32), nanosecond (u32), and a
+    /// boolean indicating if the day should move forward.
+    pub fn calculate_hour_minute_second(fraction_of_day: f64) -> (u32, u32, u32, u32, bool) {
+        let nanos_of_day = (fraction_of_day * NANOSECONDS_PER_DAY as f64).round() as u64;
+        let move_day_forward = nanos_of_day >= NANOSECONDS_PER_DAY;
+        let nanos_of_day = nanos_of_day % NANOSECONDS_PER_DAY;
+
+        let seconds_of_day = nanos_of_day / NANOSECONDS_PER_SECOND;
+        let hour = (seconds_of_day / 3_600) as u32;
+        let minute = (seconds_of_day % 3_600 / 60) as u32;
+        let second = (seconds_of_day % 60) as u32;
+        let nanosecond = (nanos_of_day % NANOSECONDS_PER_SECOND) as u32;
 
-        Ok((hour, minute, second, move_day_forward))
+        (hour, minute, second, nanosecond, move_day_forward)
     }
 }
 
@@ -122,6 +275,13 @@ mod time_utils {
 pub trait JulianDateTimeUtc {
     /// Converts a Julian Day number to a `DateTime<Utc>`.
     ///
+    /// The date is expressed in the proleptic Gregorian calendar, also before the Gregorian
+    /// reform of 1582. Use [`HistoricalDate`] for the calendar in use at the time.
+    ///
+    /// The time of day is rounded to the nearest nanosecond. Note that an `f64` Julian Day in the
+    /// current era only resolves about 40 microseconds, so the digits below that are not
+    /// significant.
+    ///
     /// # Arguments
     /// * `julian_day` - The Julian Day number to convert.
     ///
@@ -133,20 +293,75 @@ pub trait JulianDateTimeUtc {
     fn from_julian_day(julian_day: f64) -> Result<Self, AnnualSolarEventError>
     where
         Self: Sized;
+
+    /// Converts the date and time to a Julian Day number.
+    ///
+    /// The date is interpreted in the proleptic Gregorian calendar, like all chrono dates. Use
+    /// [`JulianCalendarDate`] for dates in the Julian calendar.
+    ///
+    /// # Returns
+    /// The Julian Day number as a floating-point number.
+    ///
+    /// # Example
+    /// ```
+    /// use chrono::{DateTime, Utc};
+    /// use solar_calendar_events::JulianDateTimeUtc;
+    ///
+    /// let date_time: DateTime<Utc> = "2000-01-01T12:00:00Z".parse().unwrap();
+    ///
+    /// assert_eq!(date_time.to_julian_day(), 2_451_545.0);
+    /// ```
+    fn to_julian_day(&self) -> f64;
+
+    /// Converts the date and time to a Modified Julian Day number (JD − 2400000.5).
+    ///
+    /// # Returns
+    /// The Modified Julian Day number as a floating-point number.
+    fn to_modified_julian_day(&self) -> f64 {
+        self.to_julian_day() - meeus::MJD_OFFSET
+    }
+
+    /// Returns the number of Julian centuries of 36525 days since J2000.0 (JD 2451545.0).
+    ///
+    /// No conversion between time scales is made, so the result is in the time scale of the date
+    /// and time.
+    ///
+    /// # Returns
+    /// The number of Julian centuries as a floating-point number.
+    fn julian_centuries_since_j2000(&self) -> f64 {
+        (self.to_julian_day() - meeus::J2000) / meeus::DAYS_PER_JULIAN_CENTURY
+    }
+}
+
+/// Keeps `AnnualSolarEvent` from being implemented outside this crate, so that methods can be
+/// added to it without breaking downstream code.
+mod sealed {
+    use chrono::{DateTime, Utc};
+
+    pub trait Sealed {
+        /// Creates the event from its instant in Universal Time and Terrestrial Time.
+        fn new(julian_day: f64, julian_ephemeris_day: f64, date_time: DateTime<Utc>) -> Self
+        where
+            Self: Sized;
+    }
 }
 
 /// Trait representing the characteristics of an annual solar event (e.g., Equinox or Solstice).
-pub trait AnnualSolarEvent {
+///
+/// The trait is sealed: it is implemented by the event types of this crate and cannot be
+/// implemented for other types.
+pub trait AnnualSolarEvent: sealed::Sealed {
     /// Creates an instance of the solar event for a given year.
     ///
     /// # Arguments
-    /// * `year` - The year for which to calculate the solar event.
+    /// * `year` - The year for which to calculate the solar event, using astronomical year
+    ///   numbering (year 0 is 1 BCE, year -1 is 2 BCE, and so on).
     ///
     /// # Returns
     /// An instance of the solar event for the specified year.
     ///
     /// # Errors
-    /// Returns an error if the year is out of range (1900-2100) or if the date and time cannot be
+    /// Returns an error if the year is outside `year_range` or if the date and time cannot be
     /// calculated.
     ///
     /// # Example
@@ -157,35 +372,140 @@ pub trait AnnualSolarEvent {
     ///
     /// assert_eq!(event.year(), 2021);
     ///
-    /// let out_of_range_event = MarchEquinox::for_year(1899);
+    /// let out_of_range_event = MarchEquinox::for_year(3001);
     ///
     /// assert!(out_of_range_event.is_err());
     ///
     /// assert_eq!(
     ///     out_of_range_event.err(),
-    ///     Some(solar_calendar_events::AnnualSolarEventError::YearOutOfRange(1899))
+    ///     Some(solar_calendar_events::AnnualSolarEventError::YearOutOfRange(3001))
     /// );
     /// ```
     fn for_year(year: i32) -> Result<Self, AnnualSolarEventError>
     where
-        Self: Sized;
+        Self: Sized,
+    {
+        Self::for_year_with_method(year, CalculationMethod::default())
+    }
+
+    /// Creates an instance of the solar event for a given year, using the given calculation
+    /// method.
+    ///
+    /// # Arguments
+    /// * `year` - The year for which to calculate the solar event, using astronomical year
+    ///   numbering.
+    /// * `method` - The method used to calculate the instant of the solar event.
+    ///
+    /// # Returns
+    /// An instance of the solar event for the specified year.
+    ///
+    /// # Errors
+    /// Returns an error if the year is outside the range supported by `method` or if the date and
+    /// time cannot be calculated.
+    ///
+    /// # Example
+    /// ```
+    /// use solar_calendar_events::{AnnualSolarEvent, CalculationMethod, JuneSolstice};
+    ///
+    /// let event =
+    ///     JuneSolstice::for_year_with_method(5000, CalculationMethod::TruncatedVsop87).unwrap();
+    ///
+    /// assert_eq!(event.year(), 5000);
+    /// ```
+    fn for_year_with_method(
+        year: i32,
+        method: CalculationMethod,
+    ) -> Result<Self, AnnualSolarEventError>
+    where
+        Self: Sized,
+    {
+        if !method.year_range().contains(&year) {
+            return Err(AnnualSolarEventError::YearOutOfRange(year));
+        }
+        let julian_ephemeris_day = Self::calculate_julian_day_with_method(year, method);
+        let julian_day = delta_t::universal_julian_day(julian_ephemeris_day);
+        let date_time = Self::utc_from_julian(julian_day)?;
+        Ok(Self::new(julian_day, julian_ephemeris_day, date_time))
+    }
 
     /// Returns the date and time of the solar event as a `DateTime<Utc>`.
     ///
+    /// The instant is converted from Terrestrial Time to Universal Time using `delta_t`. UT and
+    /// UTC never differ by more than 0.9 seconds.
+    ///
     /// # Returns
     /// A `DateTime<Utc>` representing the date and time of the solar event.
     fn date_time(&self) -> DateTime<Utc>;
 
-    /// Returns the Julian Day Number of the solar event.
+    /// Returns the date of the solar event in the calendar in use at the time: the Julian calendar
+    /// before 1582-10-15 and the Gregorian calendar from then on.
+    ///
+    /// # Returns
+    /// The date of the solar event, while `date_time` always uses the proleptic Gregorian
+    /// calendar.
+    ///
+    /// # Errors
+    /// Returns an error if the date cannot be converted to the Julian calendar.
+    ///
+    /// # Example
+    /// ```
+    /// use solar_calendar_events::{AnnualSolarEvent, HistoricalDate, MarchEquinox};
+    ///
+    /// let equinox = MarchEquinox::for_year(1000).unwrap();
+    ///
+    /// let date = equinox.historical_date().unwrap();
+    ///
+    /// assert_eq!(equinox.date_time().format("%F").to_string(), "1000-03-20");
+    /// assert!(matches!(date, HistoricalDate::Julian(_)));
+    /// assert_eq!(date.to_string(), "1000-03-14");
+    /// ```
+    fn historical_date(&self) -> Result<HistoricalDate, AnnualSolarEventError> {
+        HistoricalDate::from_julian_day(self.julian_day())
+    }
+
+    /// Returns the Julian Day Number of the solar event in Universal Time.
     ///
     /// # Returns
     /// The Julian Day Number of the solar event as a floating-point number.
     fn julian_day(&self) -> f64;
 
+    /// Returns the Julian Ephemeris Day of the solar event, i.e. the Julian Day in Terrestrial
+    /// (Dynamical) Time.
+    ///
+    /// # Returns
+    /// The Julian Ephemeris Day of the solar event as a floating-point number.
+    fn julian_ephemeris_day(&self) -> f64;
+
     /// Returns the year for which the solar event is calculated.
     fn year(&self) -> i32;
 
-    /// Validates whether the given year is within the valid range (1900-2100).
+    /// Returns the apparent position of the Sun at the instant of the solar event.
+    ///
+    /// # Returns
+    /// The position of the Sun, whose apparent longitude is close to `solar_longitude`.
+    ///
+    /// # Example
+    /// ```
+    /// use solar_calendar_events::{AnnualSolarEvent, SeptemberEquinox};
+    ///
+    /// let position = SeptemberEquinox::for_year(2024).unwrap().sun_position();
+    ///
+    /// assert!((position.longitude() - 180.0).abs() < 0.001);
+    /// assert!(position.declination().abs() < 0.001);
+    /// ```
+    fn sun_position(&self) -> SunPosition {
+        SunPosition::from_julian_ephemeris_day(self.julian_ephemeris_day())
+    }
+
+    /// Returns the range of years supported by the algorithm used to calculate the solar event.
+    ///
+    /// # Returns
+    /// The inclusive range of supported years, using astronomical year numbering.
+    fn year_range() -> RangeInclusive<i32> {
+        CalculationMethod::default().year_range()
+    }
+
+    /// Validates whether the given year is within the range returned by `year_range`.
     ///
     /// # Arguments
     /// * `year` - The year to validate.
@@ -194,40 +514,103 @@ pub trait AnnualSolarEvent {
     /// An `Ok(())` if the year is within the valid range, otherwise an error.
     ///
     /// # Errors
-    /// Returns an error if the year is out of range (1900-2100).
+    /// Returns an error if the year is out of range.
     fn year_in_range(year: i32) -> Result<(), AnnualSolarEventError> {
-        if !(1_900..=2_100).contains(&year) {
+        if !Self::year_range().contains(&year) {
             return Err(AnnualSolarEventError::YearOutOfRange(year));
         }
         Ok(())
     }
 
-    /// Returns constants needed to calculate the Julian Day Number for the solar event.
+    /// Returns the apparent geocentric longitude of the Sun at the instant of the solar event.
+    ///
+    /// # Returns
+    /// The longitude in degrees.
+    fn solar_longitude() -> f64;
+
+    /// Returns constants needed to calculate the Julian Day Number for the solar event in the
+    /// years 1000 to 3000 (Meeus, Table 27.B).
     ///
     /// # Returns
     /// A tuple containing the base, factor, and coefficients for the Julian Day calculation.
     fn julian_day_constants() -> (f64, f64, f64, f64, f64);
 
-    /// Calculates the Julian Day Number for the event in a given year.
+    /// Returns constants needed to calculate the Julian Day Number for the solar event in the
+    /// years -1000 to 1000 (Meeus, Table 27.A).
+    ///
+    /// The polynomial is in the year divided by 1000, while `julian_day_constants` is in the years
+    /// since 2000 divided by 1000. By default, the polynomial of `julian_day_constants` is
+    /// rewritten in the year divided by 1000, which extrapolates it to the earlier years.
+    ///
+    /// # Returns
+    /// A tuple containing the base, factor, and coefficients for the Julian Day calculation.
+    fn julian_day_constants_before_year_1000() -> (f64, f64, f64, f64, f64) {
+        // Substitutes m - 2 for m in the polynomial of `julian_day_constants`.
+        let (base, factor, m2_coeff, m3_coeff, m4_coeff) = Self::julian_day_constants();
+        (
+            base - 2.0 * factor + 4.0 * m2_coeff - 8.0 * m3_coeff + 16.0 * m4_coeff,
+            factor - 4.0 * m2_coeff + 12.0 * m3_coeff - 32.0 * m4_coeff,
+            m2_coeff - 6.0 * m3_coeff + 24.0 * m4_coeff,
+            m3_coeff - 8.0 * m4_coeff,
+            m4_coeff,
+        )
+    }
+
+    /// Calculates the Julian Ephemeris Day for the event in a given year.
+    ///
+    /// The mean instant is taken from the polynomial in `julian_day_constants_before_year_1000`
+    /// for years before 1000 and from `julian_day_constants` otherwise, and then refined with the
+    /// periodic terms from Meeus, *Astronomical Algorithms*, Table 27.C.
     ///
     /// # Arguments
     /// * `year` - The year for which to calculate the Julian Day Number.
     ///
     /// # Returns
-    /// The Julian Day Number as a floating-point number for the event in the specified year.
+    /// The Julian Ephemeris Day as a floating-point number for the event in the specified year.
     fn calculate_julian_day(year: i32) -> f64 {
-        let (base, factor, m2_coeff, m3_coeff, m4_coeff) = Self::julian_day_constants();
+        let ((base, factor, m2_coeff, m3_coeff, m4_coeff), m) =
+            if year < meeus::TABLE_27_B_FIRST_YEAR {
+                (
+                    Self::julian_day_constants_before_year_1000(),
+                    year as f64 / 1000.0,
+                )
+            } else {
+                (
+                    Self::julian_day_constants(),
+                    (year as f64 - 2000.0) / 1000.0,
+                )
+            };
 
-        let m = (year as f64 - 2000.0) / 1000.0;
         let m2 = m * m;
         let m3 = m2 * m;
         let m4 = m3 * m;
 
-        let f: f64 = base + factor * m + m2_coeff * m2 + m3_coeff * m3 + m4_coeff * m4;
+        let jde0: f64 = base + factor * m + m2_coeff * m2 + m3_coeff * m3 + m4_coeff * m4;
+        let f: f64 = meeus::periodic_correction(jde0);
+
+        f.to_five_decimals()
+    }
 
-        match f.to_five_decimals() {
-            Ok(jd) => jd,
-            Err(_) => f,
+    /// Calculates the Julian Ephemeris Day for the event in a given year, using the given
+    /// calculation method.
+    ///
+    /// With `CalculationMethod::TruncatedVsop87`, the result of `calculate_julian_day` is used as
+    /// the starting point for solving for the instant when the Sun reaches `solar_longitude`.
+    /// Years outside the Meeus tables start from the nearest table, extrapolated.
+    ///
+    /// # Arguments
+    /// * `year` - The year for which to calculate the Julian Day Number.
+    /// * `method` - The method used to calculate the Julian Ephemeris Day.
+    ///
+    /// # Returns
+    /// The Julian Ephemeris Day as a floating-point number for the event in the specified year.
+    fn calculate_julian_day_with_method(year: i32, method: CalculationMethod) -> f64 {
+        match method {
+            CalculationMethod::Meeus => Self::calculate_julian_day(year),
+            CalculationMethod::TruncatedVsop87 => vsop87::solve_solar_longitude(
+                Self::solar_longitude(),
+                Self::calculate_julian_day(year),
+            ),
         }
     }
 
@@ -248,16 +631,31 @@ pub trait AnnualSolarEvent {
 
 /// Trait for working with floating-point numbers to round them to five decimal places.
 trait JulianDayNumber {
-    /// Rounds the value to five decimal places.
+    /// Rounds the value to five decimal places, with exact halfway cases rounded to even.
     ///
-    /// Returns an error if the rounding or parsing fails.
-    fn to_five_decimals(&self) -> Result<f64, AnnualSolarEventError>;
+    /// The result is the `f64` closest to the rounded decimal value, the same value that
+    /// formatting with `{:.5}` and parsing the result back would give, but without allocating.
+    fn to_five_decimals(&self) -> f64;
 }
 
 impl JulianDayNumber for f64 {
-    fn to_five_decimals(&self) -> Result<Self, AnnualSolarEventError> {
-        let s = format!("{:.5}", self);
-        s.parse().map_err(AnnualSolarEventError::ParseFloatError)
+    fn to_five_decimals(&self) -> Self {
+        let scaled = self * 100_000.0;
+        let rounded = if (scaled - scaled.trunc()).abs() == 0.5 {
+            // The multiplication may have rounded onto a halfway point, so let the exact
+            // remainder of the multiplication decide which way to round.
+            let remainder = self.mul_add(100_000.0, -scaled);
+            if remainder > 0.0 {
+                scaled.ceil()
+            } else if remainder < 0.0 {
+                scaled.floor()
+            } else {
+                scaled.round_ties_even()
+            }
+        } else {
+            scaled.round()
+        };
+        rounded / 100_000.0
     }
 }
 
@@ -266,23 +664,12 @@ impl JulianDateTimeUtc for DateTime<Utc> {
     ///
     /// Returns an error if the conversion fails due to invalid date or time components.
     fn from_julian_day(jdn: f64) -> Result<DateTime<Utc>, AnnualSolarEventError> {
-        let j: f64 = jdn.to_five_decimals()? + 0.5;
-        let z: i32 = j as i32;
+        let j: f64 = jdn + 0.5;
+        let z: i32 = j.floor() as i32;
         let f: f64 = j - z as f64;
-        let a: i32 = if z < 2_299_161 {
-            z
-        } else {
-            let alpha: i32 = ((z as f64 - 1_867_216.25) / 36_524.25) as i32;
-            z + 1 + (alpha - ((alpha as f64 / 4.0) as i32))
-        };
-        let b: i32 = a + 1_524;
-        let c: i32 = ((b as f64 - 122.1) / 365.25) as i32;
-        let d: i32 = (365.25 * c as f64) as i32;
-        let e: i32 = ((b - d) as f64 / 30.6) as i32;
-        let (month, year) = time_utils::calculate_month_and_year(e, c)?;
-        let (day, fraction_of_day) = time_utils::calculate_day(f, b, d, e);
-        let (hour, minute, second, move_day_forward) =
-            time_utils::calculate_hour_minute_second(fraction_of_day)?;
+        let (year, month, day) = time_utils::calculate_date(z, Calendar::Gregorian)?;
+        let (hour, minute, second, nanosecond, move_day_forward) =
+            time_utils::calculate_hour_minute_second(f);
 
         let naive_date = match NaiveDate::from_ymd_opt(year, month, day) {
             Some(d) => {
@@ -295,36 +682,63 @@ impl JulianDateTimeUtc for DateTime<Utc> {
             None => return Err(AnnualSolarEventError::InvalidDateError(year, month, day)),
         };
 
-        let naive_time: NaiveTime = match NaiveTime::from_hms_opt(hour, minute, second) {
-            Some(t) => t,
-            None => return Err(AnnualSolarEventError::NaiveTimeError(hour, minute, second)),
-        };
+        let naive_time: NaiveTime =
+            match NaiveTime::from_hms_nano_opt(hour, minute, second, nanosecond) {
+                Some(t) => t,
+                None => return Err(AnnualSolarEventError::NaiveTimeError(hour, minute, second)),
+            };
 
         Ok(DateTime::from_naive_utc_and_offset(
             NaiveDateTime::new(naive_date, naive_time),
             Utc,
         ))
     }
+
+    fn to_julian_day(&self) -> f64 {
+        self.naive_utc().to_julian_day()
+    }
+}
+
+impl JulianDateTimeUtc for NaiveDateTime {
+    /// Converts a Julian Day number to a `NaiveDateTime` in UTC.
+    ///
+    /// Returns an error if the conversion fails due to invalid date or time components.
+    fn from_julian_day(jdn: f64) -> Result<NaiveDateTime, AnnualSolarEventError> {
+        DateTime::<Utc>::from_julian_day(jdn).map(|date_time| date_time.naive_utc())
+    }
+
+    fn to_julian_day(&self) -> f64 {
+        let seconds =
+            self.num_seconds_from_midnight() as f64 + self.nanosecond() as f64 / 1_000_000_000.0;
+        time_utils::calculate_julian_day(
+            self.year(),
+            self.month(),
+            self.day(),
+            seconds / 86_400.0,
+            Calendar::Gregorian,
+        )
+    }
 }
 
 /// Represents the March Equinox for a specific year.
 #[derive(Debug)]
 pub struct MarchEquinox {
     julian_day: f64,
+    julian_ephemeris_day: f64,
     date_time: DateTime<Utc>,
 }
 
-impl AnnualSolarEvent for MarchEquinox {
-    fn for_year(year: i32) -> Result<Self, AnnualSolarEventError> {
-        Self::year_in_range(year)?;
-        let julian_day = Self::calculate_julian_day(year);
-        let date_time = Self::utc_from_julian(julian_day)?;
-        Ok(Self {
+impl sealed::Sealed for MarchEquinox {
+    fn new(julian_day: f64, julian_ephemeris_day: f64, date_time: DateTime<Utc>) -> Self {
+        Self {
             julian_day,
+            julian_ephemeris_day,
             date_time,
-        })
+        }
     }
+}
 
+impl AnnualSolarEvent for MarchEquinox {
     fn date_time(&self) -> DateTime<Utc> {
         self.date_time
     }
@@ -333,10 +747,18 @@ impl AnnualSolarEvent for MarchEquinox {
         self.julian_day
     }
 
+    fn julian_ephemeris_day(&self) -> f64 {
+        self.julian_ephemeris_day
+    }
+
     fn year(&self) -> i32 {
         self.date_time.year()
     }
 
+    fn solar_longitude() -> f64 {
+        0.0
+    }
+
     fn julian_day_constants() -> (f64, f64, f64, f64, f64) {
         (
             2_451_623.809_84,
@@ -346,26 +768,37 @@ impl AnnualSolarEvent for MarchEquinox {
             -0.000_57,
         )
     }
+
+    fn julian_day_constants_before_year_1000() -> (f64, f64, f64, f64, f64) {
+        (
+            1_721_139.291_89,
+            365_242.137_40,
+            0.061_34,
+            0.001_11,
+            -0.000_71,
+        )
+    }
 }
 
 /// Represents the June Solstice for a specific year.
 #[derive(Debug)]
 pub struct JuneSolstice {
     julian_day: f64,
+    julian_ephemeris_day: f64,
     date_time: DateTime<Utc>,
 }
 
-impl AnnualSolarEvent for JuneSolstice {
-    fn for_year(year: i32) -> Result<Self, AnnualSolarEventError> {
-        Self::year_in_range(year)?;
-        let julian_day = Self::calculate_julian_day(year);
-        let date_time = Self::utc_from_julian(julian_day)?;
-        Ok(Self {
+impl sealed::Sealed for JuneSolstice {
+    fn new(julian_day: f64, julian_ephemeris_day: f64, date_time: DateTime<Utc>) -> Self {
+        Self {
             julian_day,
+            julian_ephemeris_day,
             date_time,
-        })
+        }
     }
+}
 
+impl AnnualSolarEvent for JuneSolstice {
     fn date_time(&self) -> DateTime<Utc> {
         self.date_time
     }
@@ -374,17 +807,35 @@ impl AnnualSolarEvent for JuneSolstice {
         self.julian_day
     }
 
+    fn julian_ephemeris_day(&self) -> f64 {
+        self.julian_ephemeris_day
+    }
+
     fn year(&self) -> i32 {
         self.date_time.year()
     }
 
+    fn solar_longitude() -> f64 {
+        90.0
+    }
+
     fn julian_day_constants() -> (f64, f64, f64, f64, f64) {
         (
             2_451_716.567_67,
             365_241.626_03,
             0.003_25,
             0.008_88,
-            0.000_30,
+            -0.000_30,
+        )
+    }
+
+    fn julian_day_constants_before_year_1000() -> (f64, f64, f64, f64, f64) {
+        (
+            1_721_233.254_01,
+            365_241.725_62,
+            -0.053_23,
+            0.009_07,
+            0.000_25,
         )
     }
 }
@@ -393,21 +844,21 @@ impl AnnualSolarEvent for JuneSolstice {
 #[derive(Debug)]
 pub struct SeptemberEquinox {
     julian_day: f64,
+    julian_ephemeris_day: f64,
     date_time: DateTime<Utc>,
 }
 
-impl AnnualSolarEvent for SeptemberEquinox {
-    fn for_year(year: i32) -> Result<Self, AnnualSolarEventError> {
-        Self::year_in_range(year)?;
-        let julian_day = Self::calculate_julian_day(year);
-        let date_time = Self::utc_from_julian(julian_day)?;
-
-        Ok(Self {
+impl sealed::Sealed for SeptemberEquinox {
+    fn new(julian_day: f64, julian_ephemeris_day: f64, date_time: DateTime<Utc>) -> Self {
+        Self {
             julian_day,
+            julian_ephemeris_day,
             date_time,
-        })
+        }
     }
+}
 
+impl AnnualSolarEvent for SeptemberEquinox {
     fn date_time(&self) -> DateTime<Utc> {
         self.date_time
     }
@@ -416,17 +867,35 @@ impl AnnualSolarEvent for SeptemberEquinox {
         self.julian_day
     }
 
+    fn julian_ephemeris_day(&self) -> f64 {
+        self.julian_ephemeris_day
+    }
+
     fn year(&self) -> i32 {
         self.date_time.year()
     }
 
+    fn solar_longitude() -> f64 {
+        180.0
+    }
+
     fn julian_day_constants() -> (f64, f64, f64, f64, f64) {
         (
             2_451_810.217_15,
             365_242.017_67,
-            0.003_37,
-            -0.000_78,
             -0.115_75,
+            0.003_37,
+            0.000_78,
+        )
+    }
+
+    fn julian_day_constants_before_year_1000() -> (f64, f64, f64, f64, f64) {
+        (
+            1_721_325.704_55,
+            365_242.495_58,
+            -0.116_77,
+            -0.002_97,
+            0.000_74,
         )
     }
 }
@@ -435,21 +904,21 @@ impl AnnualSolarEvent for SeptemberEquinox {
 #[derive(Debug)]
 pub struct DecemberSolstice {
     julian_day: f64,
+    julian_ephemeris_day: f64,
     date_time: DateTime<Utc>,
 }
 
-impl AnnualSolarEvent for DecemberSolstice {
-    fn for_year(year: i32) -> Result<Self, AnnualSolarEventError> {
-        Self::year_in_range(year)?;
-        let julian_day = Self::calculate_julian_day(year);
-        let date_time = Self::utc_from_julian(julian_day)?;
-
-        Ok(Self {
+impl sealed::Sealed for DecemberSolstice {
+    fn new(julian_day: f64, julian_ephemeris_day: f64, date_time: DateTime<Utc>) -> Self {
+        Self {
             julian_day,
+            julian_ephemeris_day,
             date_time,
-        })
+        }
     }
+}
 
+impl AnnualSolarEvent for DecemberSolstice {
     fn date_time(&self) -> DateTime<Utc> {
         self.date_time
     }
@@ -458,34 +927,54 @@ impl AnnualSolarEvent for DecemberSolstice {
         self.julian_day
     }
 
+    fn julian_ephemeris_day(&self) -> f64 {
+        self.julian_ephemeris_day
+    }
+
     fn year(&self) -> i32 {
         self.date_time.year()
     }
 
+    fn solar_longitude() -> f64 {
+        270.0
+    }
+
     fn julian_day_constants() -> (f64, f64, f64, f64, f64) {
         (
             2_451_900.059_52,
             365_242.740_49,
-            0.000_32,
             -0.062_23,
             -0.008_23,
+            0.000_32,
+        )
+    }
+
+    fn julian_day_constants_before_year_1000() -> (f64, f64, f64, f64, f64) {
+        (
+            1_721_414.399_87,
+            365_242.882_57,
+            -0.007_69,
+            -0.009_33,
+            -0.000_06,
         )
     }
 }
 
 /// Contains all four solar events (March Equinox, June Solstice, September Equinox, and December
-/// Solstice) for a given year.
+/// Solstice) for a given year, together with the perihelion and aphelion of the Earth.
 #[derive(Debug)]
 pub struct AnnualSolarEvents {
     march_equinox: MarchEquinox,
     june_solstice: JuneSolstice,
     september_equinox: SeptemberEquinox,
     december_solstice: DecemberSolstice,
+    perihelion: Option<Perihelion>,
+    aphelion: Aphelion,
 }
 
 impl AnnualSolarEvents {
     /// Creates a new `AnnualSolarEvents` instance for the specified year, which contains all four
-    /// solar events.
+    /// solar events and the perihelion and aphelion.
     ///
     /// Returns an error if the year is outside the valid range.
     pub fn for_year(year: i32) -> Result<Self, AnnualSolarEventError> {
@@ -494,6 +983,11 @@ impl AnnualSolarEvents {
             june_solstice: JuneSolstice::for_year(year)?,
             september_equinox: SeptemberEquinox::for_year(year)?,
             december_solstice: DecemberSolstice::for_year(year)?,
+            perihelion: match Perihelion::for_year(year) {
+                Err(AnnualSolarEventError::NoEventInYear(_)) => None,
+                perihelion => Some(perihelion?),
+            },
+            aphelion: Aphelion::for_year(year)?,
         })
     }
 
@@ -517,8 +1011,81 @@ impl AnnualSolarEvents {
         &self.december_solstice
     }
 
+    /// Returns a reference to the perihelion of the Earth, or `None` in the years around 1800
+    /// without a perihelion.
+    pub fn perihelion(&self) -> Option<&Perihelion> {
+        self.perihelion.as_ref()
+    }
+
+    /// Returns a reference to the aphelion of the Earth.
+    pub fn aphelion(&self) -> &Aphelion {
+        &self.aphelion
+    }
+
+    /// Returns the equinox or solstice of the given kind.
+    ///
+    /// # Example
+    /// ```
+    /// use solar_calendar_events::{AnnualSolarEvents, SolarEventKind};
+    ///
+    /// let events = AnnualSolarEvents::for_year(2024).unwrap();
+    /// let kind: SolarEventKind = "december_solstice".parse().unwrap();
+    ///
+    /// assert_eq!(events.get(kind).date_time().format("%F").to_string(), "2024-12-21");
+    /// ```
+    pub fn get(&self, kind: SolarEventKind) -> SolarEvent {
+        match kind {
+            SolarEventKind::MarchEquinox => SolarEvent::from(&self.march_equinox),
+            SolarEventKind::JuneSolstice => SolarEvent::from(&self.june_solstice),
+            SolarEventKind::SeptemberEquinox => SolarEvent::from(&self.september_equinox),
+            SolarEventKind::DecemberSolstice => SolarEvent::from(&self.december_solstice),
+        }
+    }
+
+    /// Returns the equinox or solstice with the given seasonal name in a hemisphere.
+    ///
+    /// Like `get`, the event is returned as a [`SolarEvent`], since the struct with the name
+    /// depends on the hemisphere.
+    ///
+    /// # Example
+    /// ```
+    /// use solar_calendar_events::{AnnualSolarEvents, Hemisphere, SeasonalEvent, SolarEventKind};
+    ///
+    /// let events = AnnualSolarEvents::for_year(2024).unwrap();
+    /// let winter = events.seasonal(SeasonalEvent::WinterSolstice, Hemisphere::Southern);
+    ///
+    /// assert_eq!(winter, events.get(SolarEventKind::JuneSolstice));
+    /// ```
+    pub fn seasonal(&self, event: SeasonalEvent, hemisphere: Hemisphere) -> SolarEvent {
+        self.get(event.kind(hemisphere))
+    }
+
+    /// Returns an iterator over the four equinoxes and solstices in chronological order, starting
+    /// with the March Equinox.
+    pub fn iter(&self) -> SolarEventsIter {
+        SolarEventKind::ALL.map(|kind| self.get(kind)).into_iter()
+    }
+
     /// Returns the year of these solar events.
     pub fn year(&self) -> i32 {
         self.march_equinox.year()
     }
 }
+
+impl IntoIterator for &AnnualSolarEvents {
+    type Item = SolarEvent;
+    type IntoIter = SolarEventsIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl IntoIterator for AnnualSolarEvents {
+    type Item = SolarEvent;
+    type IntoIter = SolarEventsIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
@@ -0,0 +1,373 @@
+// Copyright (c) 2023-2024, Johan Thorén.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The phases of the Moon, following Meeus, *Astronomical Algorithms* (2nd ed.), chapter 49.
+
+use std::slice;
+
+use chrono::{DateTime, Datelike, NaiveDate, Utc};
+
+use crate::{delta_t, meeus, AnnualSolarEventError, JulianDateTimeUtc};
+
+/// The Julian Ephemeris Day of the mean new Moon of 2000 January 6 (k = 0).
+const MEAN_NEW_MOON_AT_K0: f64 = 2_451_550.097_66;
+
+/// The mean synodic month, in days.
+const MEAN_SYNODIC_MONTH: f64 = 29.530_588_861;
+
+/// The number of lunations in a Julian century.
+const LUNATIONS_PER_CENTURY: f64 = 1_236.85;
+
+/// One of the four principal phases of the Moon.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum LunarPhase {
+    /// The Moon is in conjunction with the Sun.
+    NewMoon,
+    /// The Moon is 90° east of the Sun.
+    FirstQuarter,
+    /// The Moon is in opposition to the Sun.
+    FullMoon,
+    /// The Moon is 90° west of the Sun.
+    LastQuarter,
+}
+
+impl LunarPhase {
+    /// All four phases, in the order of a lunation starting with the new Moon.
+    pub const ALL: [LunarPhase; 4] = [
+        LunarPhase::NewMoon,
+        LunarPhase::FirstQuarter,
+        LunarPhase::FullMoon,
+        LunarPhase::LastQuarter,
+    ];
+
+    /// Returns the name of the phase in English.
+    pub fn name(&self) -> &'static str {
+        match self {
+            LunarPhase::NewMoon => "New Moon",
+            LunarPhase::FirstQuarter => "First Quarter",
+            LunarPhase::FullMoon => "Full Moon",
+            LunarPhase::LastQuarter => "Last Quarter",
+        }
+    }
+
+    /// Calculates the Julian Ephemeris Day of the phase in lunation `lunation`, counted from the
+    /// new Moon of 2000 January 6 (Meeus, equation 49.1 and the periodic terms that follow it).
+    fn calculate_julian_day(&self, lunation: i64) -> f64 {
+        let k = lunation as f64 + *self as u8 as f64 / 4.0;
+        let LunationArguments {
+            t,
+            jde,
+            e,
+            m,
+            m_moon,
+            f,
+            omega,
+        } = LunationArguments::new(k);
+
+        let correction = match self {
+            LunarPhase::NewMoon | LunarPhase::FullMoon => {
+                let (c1, c2, c3, c4, c5, c6, c7) = match self {
+                    LunarPhase::NewMoon => (
+                        -0.407_20, 0.172_41, 0.016_08, 0.010_39, 0.007_39, -0.005_14, 0.002_08,
+                    ),
+                    _ => (
+                        -0.406_14, 0.173_02, 0.016_14, 0.010_43, 0.007_34, -0.005_15, 0.002_09,
+                    ),
+                };
+                c1 * m_moon.sin()
+                    + c2 * e * m.sin()
+                    + c3 * (2.0 * m_moon).sin()
+                    + c4 * (2.0 * f).sin()
+                    + c5 * e * (m_moon - m).sin()
+                    + c6 * e * (m_moon + m).sin()
+                    + c7 * e * e * (2.0 * m).sin()
+                    - 0.001_11 * (m_moon - 2.0 * f).sin()
+                    - 0.000_57 * (m_moon + 2.0 * f).sin()
+                    + 0.000_56 * e * (2.0 * m_moon + m).sin()
+                    - 0.000_42 * (3.0 * m_moon).sin()
+                    + 0.000_42 * e * (m + 2.0 * f).sin()
+                    + 0.000_38 * e * (m - 2.0 * f).sin()
+                    - 0.000_24 * e * (2.0 * m_moon - m).sin()
+                    - 0.000_17 * omega.sin()
+                    - 0.000_07 * (m_moon + 2.0 * m).sin()
+                    + 0.000_04 * (2.0 * m_moon - 2.0 * f).sin()
+                    + 0.000_04 * (3.0 * m).sin()
+                    + 0.000_03 * (m_moon + m - 2.0 * f).sin()
+                    + 0.000_03 * (2.0 * m_moon + 2.0 * f).sin()
+                    - 0.000_03 * (m_moon + m + 2.0 * f).sin()
+                    + 0.000_03 * (m_moon - m + 2.0 * f).sin()
+                    - 0.000_02 * (m_moon - m - 2.0 * f).sin()
+                    - 0.000_02 * (3.0 * m_moon + m).sin()
+                    + 0.000_02 * (4.0 * m_moon).sin()
+            }
+            LunarPhase::FirstQuarter | LunarPhase::LastQuarter => {
+                let w = 0.003_06 - 0.000_38 * e * m.cos() + 0.000_26 * m_moon.cos()
+                    - 0.000_02 * (m_moon - m).cos()
+                    + 0.000_02 * (m_moon + m).cos()
+                    + 0.000_02 * (2.0 * f).cos();
+                let w = match self {
+                    LunarPhase::FirstQuarter => w,
+                    _ => -w,
+                };
+                w - 0.628_01 * m_moon.sin() + 0.171_72 * e * m.sin()
+                    - 0.011_83 * e * (m_moon + m).sin()
+                    + 0.008_62 * (2.0 * m_moon).sin()
+                    + 0.008_04 * (2.0 * f).sin()
+                    + 0.004_54 * e * (m_moon - m).sin()
+                    + 0.002_04 * e * e * (2.0 * m).sin()
+                    - 0.001_80 * (m_moon - 2.0 * f).sin()
+                    - 0.000_70 * (m_moon + 2.0 * f).sin()
+                    - 0.000_40 * (3.0 * m_moon).sin()
+                    - 0.000_34 * e * (2.0 * m_moon - m).sin()
+                    + 0.000_32 * e * (m + 2.0 * f).sin()
+                    + 0.000_32 * e * (m - 2.0 * f).sin()
+                    - 0.000_28 * e * e * (m_moon + 2.0 * m).sin()
+                    + 0.000_27 * e * (2.0 * m_moon + m).sin()
+                    - 0.000_17 * omega.sin()
+                    - 0.000_05 * (m_moon - m - 2.0 * f).sin()
+                    + 0.000_04 * (2.0 * m_moon + 2.0 * f).sin()
+                    - 0.000_04 * (m_moon + m + 2.0 * f).sin()
+                    + 0.000_04 * (m_moon - 2.0 * m).sin()
+                    + 0.000_03 * (m_moon + m - 2.0 * f).sin()
+                    + 0.000_03 * (3.0 * m).sin()
+                    + 0.000_02 * (2.0 * m_moon - 2.0 * f).sin()
+                    + 0.000_02 * (m_moon - m + 2.0 * f).sin()
+                    - 0.000_02 * (3.0 * m_moon + m).sin()
+            }
+        };
+
+        // The additional corrections for all phases, from the planetary arguments A1 to A14.
+        let planetary: f64 = [
+            (0.000_325, 299.77 + 0.107_408 * k - 0.009_173 * t * t),
+            (0.000_165, 251.88 + 0.016_321 * k),
+            (0.000_164, 251.83 + 26.651_886 * k),
+            (0.000_126, 349.42 + 36.412_478 * k),
+            (0.000_110, 84.66 + 18.206_239 * k),
+            (0.000_062, 141.74 + 53.303_771 * k),
+            (0.000_060, 207.14 + 2.453_732 * k),
+            (0.000_056, 154.84 + 7.306_860 * k),
+            (0.000_047, 34.52 + 27.261_239 * k),
+            (0.000_042, 207.19 + 0.121_824 * k),
+            (0.000_040, 291.34 + 1.844_379 * k),
+            (0.000_037, 161.72 + 24.198_154 * k),
+            (0.000_035, 239.56 + 25.513_099 * k),
+            (0.000_023, 331.55 + 3.592_518 * k),
+        ]
+        .iter()
+        .map(|(coefficient, argument)| coefficient * argument.to_radians().sin())
+        .sum();
+
+        jde + correction + planetary
+    }
+}
+
+/// The mean instant of a phase of the Moon and the fundamental arguments at that instant (Meeus,
+/// chapter 49), shared with the eclipse calculations of chapter 54.
+pub(crate) struct LunationArguments {
+    /// The time in Julian centuries since J2000.0.
+    pub t: f64,
+    /// The Julian Ephemeris Day of the mean phase.
+    pub jde: f64,
+    /// The eccentricity factor of the orbit of the Earth.
+    pub e: f64,
+    /// The mean anomaly of the Sun, in radians.
+    pub m: f64,
+    /// The mean anomaly of the Moon, in radians.
+    pub m_moon: f64,
+    /// The argument of latitude of the Moon, in radians.
+    pub f: f64,
+    /// The longitude of the ascending node of the orbit of the Moon, in radians.
+    pub omega: f64,
+}
+
+impl LunationArguments {
+    /// Calculates the arguments for `k`, the number of lunations since the new Moon of 2000
+    /// January 6, where a fractional part of 0.25, 0.5 or 0.75 selects a later phase.
+    pub fn new(k: f64) -> Self {
+        let t = k / LUNATIONS_PER_CENTURY;
+        Self {
+            t,
+            jde: MEAN_NEW_MOON_AT_K0
+                + MEAN_SYNODIC_MONTH * k
+                + t * t * (0.000_154_37 + t * (-0.000_000_150 + t * 0.000_000_000_73)),
+            e: 1.0 - t * (0.002_516 + t * 0.000_007_4),
+            m: (2.553_4 + 29.105_356_70 * k - t * t * (0.000_001_4 + t * 0.000_000_11))
+                .to_radians(),
+            m_moon: (201.564_3
+                + 385.816_935_28 * k
+                + t * t * (0.010_758_2 + t * (0.000_012_38 - t * 0.000_000_058)))
+                .to_radians(),
+            f: (160.710_8 + 390.670_502_84 * k
+                - t * t * (0.001_611_8 + t * (0.000_002_27 - t * 0.000_000_011)))
+                .to_radians(),
+            omega: (124.774_6 - 1.563_755_88 * k + t * t * (0.002_067_2 + t * 0.000_002_15))
+                .to_radians(),
+        }
+    }
+}
+
+/// Returns the number of the lunation, counted from the new Moon of 2000 January 6, one before
+/// the mean lunation containing Julian Day `julian_day` in Universal Time. The periodic terms
+/// move a phase or an eclipse by less than a day, so counting from it finds every event after
+/// `julian_day`.
+pub(crate) fn lunation_before(julian_day: f64) -> i64 {
+    ((delta_t::julian_ephemeris_day(julian_day) - MEAN_NEW_MOON_AT_K0) / MEAN_SYNODIC_MONTH).floor()
+        as i64
+        - 1
+}
+
+/// Returns the Julian Day of midnight UTC at the start of a year.
+pub(crate) fn start_of_year(year: i32) -> Result<f64, AnnualSolarEventError> {
+    NaiveDate::from_ymd_opt(year, 1, 1)
+        .map(|date| date.and_time(Default::default()).to_julian_day())
+        .ok_or(AnnualSolarEventError::InvalidDateError(year, 1, 1))
+}
+
+/// The instant of a phase of the Moon.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LunarPhaseEvent {
+    phase: LunarPhase,
+    julian_day: f64,
+    julian_ephemeris_day: f64,
+    date_time: DateTime<Utc>,
+}
+
+impl LunarPhaseEvent {
+    /// Returns the phase of the Moon.
+    pub fn phase(&self) -> LunarPhase {
+        self.phase
+    }
+
+    /// Returns the date and time of the phase as a `DateTime<Utc>`.
+    pub fn date_time(&self) -> DateTime<Utc> {
+        self.date_time
+    }
+
+    /// Returns the Julian Day Number of the phase in Universal Time.
+    pub fn julian_day(&self) -> f64 {
+        self.julian_day
+    }
+
+    /// Returns the Julian Ephemeris Day of the phase, i.e. the Julian Day in Terrestrial
+    /// (Dynamical) Time.
+    pub fn julian_ephemeris_day(&self) -> f64 {
+        self.julian_ephemeris_day
+    }
+}
+
+/// Contains the phases of the Moon within a year or a range of instants, in chronological order.
+///
+/// The instants are accurate to within a minute, limited mostly by ΔT for years far from the
+/// present.
+#[derive(Debug)]
+pub struct LunarPhases {
+    events: Vec<LunarPhaseEvent>,
+}
+
+impl LunarPhases {
+    /// Creates a new `LunarPhases` instance with the phases of the Moon in the specified year.
+    ///
+    /// Returns an error if the year is outside the valid range.
+    ///
+    /// # Example
+    /// ```
+    /// use solar_calendar_events::{LunarPhase, LunarPhases};
+    ///
+    /// let phases = LunarPhases::for_year(2024).unwrap();
+    /// let full_moons = phases.iter().filter(|event| event.phase() == LunarPhase::FullMoon);
+    ///
+    /// assert_eq!(full_moons.count(), 12);
+    /// ```
+    pub fn for_year(year: i32) -> Result<Self, AnnualSolarEventError> {
+        if !meeus::YEAR_RANGE.contains(&year) {
+            return Err(AnnualSolarEventError::YearOutOfRange(year));
+        }
+        Self::calculate(start_of_year(year)?, start_of_year(year + 1)?)
+    }
+
+    /// Creates a new `LunarPhases` instance with the phases of the Moon from `start` (inclusive)
+    /// to `end` (exclusive). The result is empty if `end` is not after `start`.
+    ///
+    /// Returns an error if either instant is in a year outside the valid range.
+    ///
+    /// # Example
+    /// ```
+    /// use chrono::{DateTime, Utc};
+    /// use solar_calendar_events::{LunarPhase, LunarPhases};
+    ///
+    /// let start: DateTime<Utc> = "2024-04-01T00:00:00Z".parse().unwrap();
+    /// let end: DateTime<Utc> = "2024-04-15T00:00:00Z".parse().unwrap();
+    /// let phases = LunarPhases::between(start, end).unwrap();
+    /// let phases: Vec<LunarPhase> = phases.iter().map(|event| event.phase()).collect();
+    ///
+    /// assert_eq!(phases, [LunarPhase::LastQuarter, LunarPhase::NewMoon]);
+    /// ```
+    pub fn between(
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<Self, AnnualSolarEventError> {
+        for year in [start.year(), end.year()] {
+            if !meeus::YEAR_RANGE.contains(&year) {
+                return Err(AnnualSolarEventError::YearOutOfRange(year));
+            }
+        }
+        Self::calculate(start.to_julian_day(), end.to_julian_day())
+    }
+
+    /// Calculates the phases from Julian Day `start` (inclusive) to `end` (exclusive), both in
+    /// Universal Time.
+    fn calculate(start: f64, end: f64) -> Result<Self, AnnualSolarEventError> {
+        let mut lunation = lunation_before(start);
+        let mut events = Vec::new();
+        loop {
+            for phase in LunarPhase::ALL {
+                let julian_ephemeris_day = phase.calculate_julian_day(lunation);
+                let julian_day = delta_t::universal_julian_day(julian_ephemeris_day);
+                if julian_day >= end {
+                    return Ok(Self { events });
+                }
+                if julian_day >= start {
+                    events.push(LunarPhaseEvent {
+                        phase,
+                        julian_day,
+                        julian_ephemeris_day,
+                        date_time: DateTime::<Utc>::from_julian_day(julian_day)?,
+                    });
+                }
+            }
+            lunation += 1;
+        }
+    }
+
+    /// Returns an iterator over the phases in chronological order.
+    pub fn iter(&self) -> LunarPhasesIter<'_> {
+        self.events.iter()
+    }
+
+    /// Returns the number of phases.
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+
+    /// Returns `true` if there are no phases.
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+}
+
+/// An iterator over the phases of the Moon in chronological order.
+pub type LunarPhasesIter<'a> = slice::Iter<'a, LunarPhaseEvent>;
+
+impl<'a> IntoIterator for &'a LunarPhases {
+    type Item = &'a LunarPhaseEvent;
+    type IntoIter = LunarPhasesIter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
@@ -0,0 +1,73 @@
+// Copyright (c) 2023-2024, Johan Thorén.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Tables and corrections from Jean Meeus, *Astronomical Algorithms* (2nd ed.), chapter 27.
+
+use std::ops::RangeInclusive;
+
+/// Julian Ephemeris Day of the standard epoch J2000.0.
+pub const J2000: f64 = 2_451_545.0;
+
+/// The offset between a Julian Day and a Modified Julian Day.
+pub const MJD_OFFSET: f64 = 2_400_000.5;
+
+/// Number of days in a Julian century.
+pub const DAYS_PER_JULIAN_CENTURY: f64 = 36_525.0;
+
+/// The years covered by Tables 27.A and 27.B, using astronomical year numbering.
+pub const YEAR_RANGE: RangeInclusive<i32> = -1_000..=3_000;
+
+/// The first year for which Table 27.B is used instead of Table 27.A.
+pub const TABLE_27_B_FIRST_YEAR: i32 = 1_000;
+
+/// The periodic terms `(A, B, C)` of Table 27.C, with `B` and `C` in degrees.
+const PERIODIC_TERMS: [(f64, f64, f64); 24] = [
+    (485.0, 324.96, 1_934.136),
+    (203.0, 337.23, 32_964.467),
+    (199.0, 342.08, 20.186),
+    (182.0, 27.85, 445_267.112),
+    (156.0, 73.14, 45_036.886),
+    (136.0, 171.52, 22_518.443),
+    (77.0, 222.54, 65_928.934),
+    (74.0, 296.72, 3_034.906),
+    (70.0, 243.58, 9_037.513),
+    (58.0, 119.81, 33_718.147),
+    (52.0, 297.17, 150.678),
+    (50.0, 21.02, 2_281.226),
+    (45.0, 247.54, 29_929.562),
+    (44.0, 325.15, 31_555.956),
+    (29.0, 60.93, 4_443.417),
+    (18.0, 155.12, 67_555.328),
+    (17.0, 288.79, 4_562.452),
+    (16.0, 198.04, 62_894.029),
+    (14.0, 199.76, 31_436.921),
+    (12.0, 95.39, 14_577.848),
+    (12.0, 287.11, 31_931.756),
+    (12.0, 320.81, 34_777.259),
+    (9.0, 227.73, 1_222.114),
+    (8.0, 15.45, 16_859.074),
+];
+
+/// Applies the periodic correction of Table 27.C to a mean equinox or solstice instant.
+///
+/// # Arguments
+/// * `jde0` - The mean instant (JDE0) obtained from the polynomials of Table 27.A or 27.B.
+///
+/// # Returns
+/// The corrected Julian Ephemeris Day of the event.
+pub fn periodic_correction(jde0: f64) -> f64 {
+    let t = (jde0 - J2000) / DAYS_PER_JULIAN_CENTURY;
+    let w = (35_999.373 * t - 2.47).to_radians();
+    let delta_lambda = 1.0 + 0.033_4 * w.cos() + 0.000_7 * (2.0 * w).cos();
+    let s: f64 = PERIODIC_TERMS
+        .iter()
+        .map(|(a, b, c)| a * (b + c * t).to_radians().cos())
+        .sum();
+
+    jde0 + 0.000_01 * s / delta_lambda
+}
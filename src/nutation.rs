@@ -0,0 +1,129 @@
+// Copyright (c) 2023-2024, Johan Thorén.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Nutation in longitude and obliquity following the IAU 1980 theory, and the obliquity of the
+//! ecliptic, as given in Meeus, *Astronomical Algorithms* (2nd ed.), chapter 22.
+
+/// The multiples of the fundamental arguments (D, M, M', F, Ω) and the coefficients
+/// `(a, b, c, d)` of each term, so that `Δψ = Σ (a + b T) sin(arg)` and
+/// `Δε = Σ (c + d T) cos(arg)`, in units of 0.0001".
+#[rustfmt::skip]
+const TERMS: [([i8; 5], [f64; 4]); 63] = [
+    ([0, 0, 0, 0, 1], [-171_996.0, -174.2, 92_025.0, 8.9]),
+    ([-2, 0, 0, 2, 2], [-13_187.0, -1.6, 5_736.0, -3.1]),
+    ([0, 0, 0, 2, 2], [-2_274.0, -0.2, 977.0, -0.5]),
+    ([0, 0, 0, 0, 2], [2_062.0, 0.2, -895.0, 0.5]),
+    ([0, 1, 0, 0, 0], [1_426.0, -3.4, 54.0, -0.1]),
+    ([0, 0, 1, 0, 0], [712.0, 0.1, -7.0, 0.0]),
+    ([-2, 1, 0, 2, 2], [-517.0, 1.2, 224.0, -0.6]),
+    ([0, 0, 0, 2, 1], [-386.0, -0.4, 200.0, 0.0]),
+    ([0, 0, 1, 2, 2], [-301.0, 0.0, 129.0, -0.1]),
+    ([-2, -1, 0, 2, 2], [217.0, -0.5, -95.0, 0.3]),
+    ([-2, 0, 1, 0, 0], [-158.0, 0.0, 0.0, 0.0]),
+    ([-2, 0, 0, 2, 1], [129.0, 0.1, -70.0, 0.0]),
+    ([0, 0, -1, 2, 2], [123.0, 0.0, -53.0, 0.0]),
+    ([2, 0, 0, 0, 0], [63.0, 0.0, 0.0, 0.0]),
+    ([0, 0, 1, 0, 1], [63.0, 0.1, -33.0, 0.0]),
+    ([2, 0, -1, 2, 2], [-59.0, 0.0, 26.0, 0.0]),
+    ([0, 0, -1, 0, 1], [-58.0, -0.1, 32.0, 0.0]),
+    ([0, 0, 1, 2, 1], [-51.0, 0.0, 27.0, 0.0]),
+    ([-2, 0, 2, 0, 0], [48.0, 0.0, 0.0, 0.0]),
+    ([0, 0, -2, 2, 1], [46.0, 0.0, -24.0, 0.0]),
+    ([2, 0, 0, 2, 2], [-38.0, 0.0, 16.0, 0.0]),
+    ([0, 0, 2, 2, 2], [-31.0, 0.0, 13.0, 0.0]),
+    ([0, 0, 2, 0, 0], [29.0, 0.0, 0.0, 0.0]),
+    ([-2, 0, 1, 2, 2], [29.0, 0.0, -12.0, 0.0]),
+    ([0, 0, 0, 2, 0], [26.0, 0.0, 0.0, 0.0]),
+    ([-2, 0, 0, 2, 0], [-22.0, 0.0, 0.0, 0.0]),
+    ([0, 0, -1, 2, 1], [21.0, 0.0, -10.0, 0.0]),
+    ([0, 2, 0, 0, 0], [17.0, -0.1, 0.0, 0.0]),
+    ([2, 0, -1, 0, 1], [16.0, 0.0, -8.0, 0.0]),
+    ([-2, 2, 0, 2, 2], [-16.0, 0.1, 7.0, 0.0]),
+    ([0, 1, 0, 0, 1], [-15.0, 0.0, 9.0, 0.0]),
+    ([-2, 0, 1, 0, 1], [-13.0, 0.0, 7.0, 0.0]),
+    ([0, -1, 0, 0, 1], [-12.0, 0.0, 6.0, 0.0]),
+    ([0, 0, 2, -2, 0], [11.0, 0.0, 0.0, 0.0]),
+    ([2, 0, -1, 2, 1], [-10.0, 0.0, 5.0, 0.0]),
+    ([2, 0, 1, 2, 2], [-8.0, 0.0, 3.0, 0.0]),
+    ([0, 1, 0, 2, 2], [7.0, 0.0, -3.0, 0.0]),
+    ([-2, 1, 1, 0, 0], [-7.0, 0.0, 0.0, 0.0]),
+    ([0, -1, 0, 2, 2], [-7.0, 0.0, 3.0, 0.0]),
+    ([2, 0, 0, 2, 1], [-7.0, 0.0, 3.0, 0.0]),
+    ([2, 0, 1, 0, 0], [6.0, 0.0, 0.0, 0.0]),
+    ([-2, 0, 2, 2, 2], [6.0, 0.0, -3.0, 0.0]),
+    ([-2, 0, 1, 2, 1], [6.0, 0.0, -3.0, 0.0]),
+    ([2, 0, -2, 0, 1], [-6.0, 0.0, 3.0, 0.0]),
+    ([2, 0, 0, 0, 1], [-6.0, 0.0, 3.0, 0.0]),
+    ([0, -1, 1, 0, 0], [5.0, 0.0, 0.0, 0.0]),
+    ([-2, -1, 0, 2, 1], [-5.0, 0.0, 3.0, 0.0]),
+    ([-2, 0, 0, 0, 1], [-5.0, 0.0, 3.0, 0.0]),
+    ([0, 0, 2, 2, 1], [-5.0, 0.0, 3.0, 0.0]),
+    ([-2, 0, 2, 0, 1], [4.0, 0.0, 0.0, 0.0]),
+    ([-2, 1, 0, 2, 1], [4.0, 0.0, 0.0, 0.0]),
+    ([0, 0, 1, -2, 0], [4.0, 0.0, 0.0, 0.0]),
+    ([-1, 0, 1, 0, 0], [-4.0, 0.0, 0.0, 0.0]),
+    ([-2, 1, 0, 0, 0], [-4.0, 0.0, 0.0, 0.0]),
+    ([1, 0, 0, 0, 0], [-4.0, 0.0, 0.0, 0.0]),
+    ([0, 0, 1, 2, 0], [3.0, 0.0, 0.0, 0.0]),
+    ([0, 0, -2, 2, 2], [-3.0, 0.0, 0.0, 0.0]),
+    ([-1, -1, 1, 0, 0], [-3.0, 0.0, 0.0, 0.0]),
+    ([0, 1, 1, 0, 0], [-3.0, 0.0, 0.0, 0.0]),
+    ([0, -1, 1, 2, 2], [-3.0, 0.0, 0.0, 0.0]),
+    ([2, -1, -1, 2, 2], [-3.0, 0.0, 0.0, 0.0]),
+    ([0, 0, 3, 2, 2], [-3.0, 0.0, 0.0, 0.0]),
+    ([2, -1, 0, 2, 2], [-3.0, 0.0, 0.0, 0.0]),
+];
+
+/// Calculates the nutation in longitude and in obliquity.
+///
+/// # Arguments
+/// * `t` - Julian centuries of Dynamical Time since J2000.0.
+///
+/// # Returns
+/// A tuple containing the nutation in longitude (Δψ) and in obliquity (Δε), in degrees.
+pub fn nutation(t: f64) -> (f64, f64) {
+    let t2 = t * t;
+    let t3 = t2 * t;
+    let arguments = [
+        297.850_36 + 445_267.111_480 * t - 0.001_914_2 * t2 + t3 / 189_474.0,
+        357.527_72 + 35_999.050_340 * t - 0.000_160_3 * t2 - t3 / 300_000.0,
+        134.962_98 + 477_198.867_398 * t + 0.008_697_2 * t2 + t3 / 56_250.0,
+        93.271_91 + 483_202.017_538 * t - 0.003_682_5 * t2 + t3 / 327_270.0,
+        125.044_52 - 1_934.136_261 * t + 0.002_070_8 * t2 + t3 / 450_000.0,
+    ];
+
+    let (delta_psi, delta_epsilon) = TERMS.iter().fold(
+        (0.0, 0.0),
+        |(delta_psi, delta_epsilon), (multiples, [a, b, c, d])| {
+            let argument: f64 = multiples
+                .iter()
+                .zip(arguments)
+                .map(|(multiple, argument)| *multiple as f64 * argument)
+                .sum::<f64>()
+                .to_radians();
+            (
+                delta_psi + (a + b * t) * argument.sin(),
+                delta_epsilon + (c + d * t) * argument.cos(),
+            )
+        },
+    );
+
+    (delta_psi / 36_000_000.0, delta_epsilon / 36_000_000.0)
+}
+
+/// Calculates the mean obliquity of the ecliptic, adopted by the IAU (Meeus, equation 22.2).
+///
+/// # Arguments
+/// * `t` - Julian centuries of Dynamical Time since J2000.0.
+///
+/// # Returns
+/// The mean obliquity of the ecliptic (ε₀), in degrees.
+pub fn mean_obliquity(t: f64) -> f64 {
+    let seconds = 21.448 - 46.815_0 * t - 0.000_59 * t * t + 0.001_813 * t * t * t;
+    23.0 + 26.0 / 60.0 + seconds / 3_600.0
+}
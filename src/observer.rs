@@ -0,0 +1,350 @@
+// Copyright (c) 2023-2024, Johan Thorén.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The rising, transit and setting of the Sun for an observer on the Earth, following Meeus,
+//! *Astronomical Algorithms* (2nd ed.), chapters 12 and 15.
+
+use chrono::{DateTime, Datelike, NaiveDate, Utc};
+
+use crate::meeus::{DAYS_PER_JULIAN_CENTURY, J2000};
+use crate::{delta_t, nutation, vsop87, AnnualSolarEventError, JulianDateTimeUtc, SunPosition};
+
+/// The altitude of the center of the Sun at sunrise and sunset for an observer at sea level, in
+/// degrees: the upper limb touches the horizon, with 34′ of atmospheric refraction and a
+/// semidiameter of 16′.
+const SUNRISE_ALTITUDE: f64 = -0.833_3;
+
+/// The dip of the horizon for an observer one meter above the surrounding terrain, in degrees.
+/// The dip grows with the square root of the elevation.
+const HORIZON_DIP_PER_SQRT_METER: f64 = 0.034_7;
+
+/// The maximum number of iterations when solving for a transit or a rising or setting.
+const MAX_ITERATIONS: usize = 10;
+
+/// The change of a solution below which the iteration stops, in days (about 10 ms).
+const CONVERGENCE: f64 = 1e-7;
+
+/// A location on the Earth, for calculating the times of sunrise and sunset.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Observer {
+    /// The geographic latitude, in degrees, positive north of the equator.
+    pub latitude: f64,
+    /// The geographic longitude, in degrees, positive east of Greenwich.
+    pub longitude: f64,
+    /// The elevation above the surrounding terrain, in meters, which lowers the visible horizon.
+    pub elevation: f64,
+}
+
+/// When the Sun crosses an altitude during a day, or whether it stays above or below it.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RiseSet {
+    /// The Sun rises above the altitude before the transit and sets below it after the transit.
+    RisesAndSets {
+        /// The instant the Sun rises above the altitude.
+        rise: DateTime<Utc>,
+        /// The instant the Sun sets below the altitude.
+        set: DateTime<Utc>,
+    },
+    /// The Sun stays above the altitude all day, as during the midnight sun.
+    AlwaysAbove,
+    /// The Sun stays below the altitude all day, as during the polar night.
+    AlwaysBelow,
+}
+
+impl RiseSet {
+    /// Returns the instant the Sun rises above the altitude, or `None` if it does not.
+    pub fn rise(&self) -> Option<DateTime<Utc>> {
+        match self {
+            RiseSet::RisesAndSets { rise, .. } => Some(*rise),
+            _ => None,
+        }
+    }
+
+    /// Returns the instant the Sun sets below the altitude, or `None` if it does not.
+    pub fn set(&self) -> Option<DateTime<Utc>> {
+        match self {
+            RiseSet::RisesAndSets { set, .. } => Some(*set),
+            _ => None,
+        }
+    }
+}
+
+/// The phases of twilight, by the depression of the center of the Sun below the horizon.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Twilight {
+    /// Civil twilight, with the Sun 6° below the horizon.
+    Civil,
+    /// Nautical twilight, with the Sun 12° below the horizon.
+    Nautical,
+    /// Astronomical twilight, with the Sun 18° below the horizon. The sky is fully dark between
+    /// astronomical dusk and dawn.
+    Astronomical,
+    /// Twilight with the Sun at a custom depression below the horizon, in degrees.
+    Custom(f64),
+}
+
+impl Twilight {
+    /// Returns the depression of the center of the Sun below the horizon, in degrees.
+    ///
+    /// # Example
+    /// ```
+    /// use solar_calendar_events::Twilight;
+    ///
+    /// assert_eq!(Twilight::Nautical.depression(), 12.0);
+    /// assert_eq!(Twilight::Custom(15.0).depression(), 15.0);
+    /// ```
+    pub fn depression(&self) -> f64 {
+        match self {
+            Twilight::Civil => 6.0,
+            Twilight::Nautical => 12.0,
+            Twilight::Astronomical => 18.0,
+            Twilight::Custom(depression) => *depression,
+        }
+    }
+}
+
+/// The transit, sunrise and sunset of the Sun on a date for an observer.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct SolarDay {
+    date: NaiveDate,
+    transit: DateTime<Utc>,
+    sunrise_sunset: RiseSet,
+}
+
+impl SolarDay {
+    /// Returns the local date of the solar day.
+    pub fn date(&self) -> NaiveDate {
+        self.date
+    }
+
+    /// Returns the instant of solar noon, when the Sun crosses the meridian of the observer.
+    pub fn transit(&self) -> DateTime<Utc> {
+        self.transit
+    }
+
+    /// Returns the instant of sunrise, or `None` if the Sun does not rise or set.
+    pub fn sunrise(&self) -> Option<DateTime<Utc>> {
+        self.sunrise_sunset.rise()
+    }
+
+    /// Returns the instant of sunset, or `None` if the Sun does not rise or set.
+    pub fn sunset(&self) -> Option<DateTime<Utc>> {
+        self.sunrise_sunset.set()
+    }
+
+    /// Returns the sunrise and sunset, or whether the Sun stays above or below the horizon.
+    pub fn sunrise_sunset(&self) -> RiseSet {
+        self.sunrise_sunset
+    }
+}
+
+impl Observer {
+    /// Creates an observer at a latitude and longitude in degrees, positive north and east, and
+    /// an elevation in meters.
+    pub fn new(latitude: f64, longitude: f64, elevation: f64) -> Self {
+        Self {
+            latitude,
+            longitude,
+            elevation,
+        }
+    }
+
+    /// Calculates the solar noon, sunrise and sunset on a date.
+    ///
+    /// The date is the local date at the longitude of the observer: the transit is the one
+    /// nearest to local mean noon, and the sunrise and sunset are the ones before and after it.
+    /// Sunrise and sunset are when the upper limb of the Sun touches the visible horizon, with
+    /// standard atmospheric refraction.
+    ///
+    /// # Errors
+    /// Returns an error if the year is outside the range supported by
+    /// `CalculationMethod::TruncatedVsop87`.
+    ///
+    /// # Example
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use solar_calendar_events::Observer;
+    ///
+    /// let stockholm = Observer::new(59.33, 18.07, 0.0);
+    /// let day = stockholm.solar_day(NaiveDate::from_ymd_opt(2024, 6, 20).unwrap()).unwrap();
+    ///
+    /// assert_eq!(day.sunrise().unwrap().format("%H:%M").to_string(), "01:30");
+    /// assert_eq!(day.sunset().unwrap().format("%H:%M").to_string(), "20:08");
+    /// ```
+    pub fn solar_day(&self, date: NaiveDate) -> Result<SolarDay, AnnualSolarEventError> {
+        if !vsop87::YEAR_RANGE.contains(&date.year()) {
+            return Err(AnnualSolarEventError::YearOutOfRange(date.year()));
+        }
+        let transit = self.transit_julian_day(date);
+        Ok(SolarDay {
+            date,
+            transit: DateTime::<Utc>::from_julian_day(transit)?,
+            sunrise_sunset: self.rise_set_julian_day(transit, self.sunrise_altitude())?,
+        })
+    }
+
+    /// Calculates the dawn and dusk of a twilight on a date, when the center of the Sun is at the
+    /// depression of `twilight` below the horizon before and after the transit.
+    ///
+    /// The date is the local date, as for [`Observer::solar_day`], so the dusk is in the evening of
+    /// the date and the dawn in the morning. The depression is geometric, without refraction or
+    /// the dip of the horizon. `RiseSet::AlwaysAbove` means the twilight never ends, as in the
+    /// white nights of summer, and `RiseSet::AlwaysBelow` that it never begins.
+    ///
+    /// # Errors
+    /// Returns an error if the year is outside the range supported by
+    /// `CalculationMethod::TruncatedVsop87`.
+    pub fn twilight(
+        &self,
+        date: NaiveDate,
+        twilight: Twilight,
+    ) -> Result<RiseSet, AnnualSolarEventError> {
+        if !vsop87::YEAR_RANGE.contains(&date.year()) {
+            return Err(AnnualSolarEventError::YearOutOfRange(date.year()));
+        }
+        self.rise_set_julian_day(self.transit_julian_day(date), -twilight.depression())
+    }
+
+    /// Calculates the dawn of a twilight on a date, when the center of the Sun rises to the
+    /// depression of `twilight` below the horizon.
+    ///
+    /// Returns `None` if the twilight never ends or never begins on the date.
+    ///
+    /// # Errors
+    /// Returns an error if the year is outside the range supported by
+    /// `CalculationMethod::TruncatedVsop87`.
+    pub fn dawn(
+        &self,
+        date: NaiveDate,
+        twilight: Twilight,
+    ) -> Result<Option<DateTime<Utc>>, AnnualSolarEventError> {
+        Ok(self.twilight(date, twilight)?.rise())
+    }
+
+    /// Calculates the dusk of a twilight on a date, when the center of the Sun sets to the
+    /// depression of `twilight` below the horizon.
+    ///
+    /// Returns `None` if the twilight never ends or never begins on the date.
+    ///
+    /// # Errors
+    /// Returns an error if the year is outside the range supported by
+    /// `CalculationMethod::TruncatedVsop87`.
+    ///
+    /// # Example
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use solar_calendar_events::{Observer, Twilight};
+    ///
+    /// let stockholm = Observer::new(59.33, 18.07, 0.0);
+    /// let midsummer = NaiveDate::from_ymd_opt(2024, 6, 20).unwrap();
+    ///
+    /// assert!(stockholm.dusk(midsummer, Twilight::Civil).unwrap().is_some());
+    /// assert!(stockholm.dusk(midsummer, Twilight::Nautical).unwrap().is_none());
+    /// ```
+    pub fn dusk(
+        &self,
+        date: NaiveDate,
+        twilight: Twilight,
+    ) -> Result<Option<DateTime<Utc>>, AnnualSolarEventError> {
+        Ok(self.twilight(date, twilight)?.set())
+    }
+
+    /// Returns the altitude of the center of the Sun at sunrise and sunset, lowered by the dip of
+    /// the horizon for the elevation of the observer.
+    fn sunrise_altitude(&self) -> f64 {
+        SUNRISE_ALTITUDE - HORIZON_DIP_PER_SQRT_METER * self.elevation.max(0.0).sqrt()
+    }
+
+    /// Calculates the Julian Day of the transit of the Sun nearest to local mean noon on a date.
+    fn transit_julian_day(&self, date: NaiveDate) -> f64 {
+        let midnight = date.and_time(Default::default()).to_julian_day();
+        let mut julian_day = midnight + 0.5 - self.longitude / 360.0;
+        for _ in 0..MAX_ITERATIONS {
+            let (hour_angle, _) = self.hour_angle_and_declination(julian_day);
+            let correction = -hour_angle / 360.0;
+            julian_day += correction;
+            if correction.abs() < CONVERGENCE {
+                break;
+            }
+        }
+        julian_day
+    }
+
+    /// Calculates when the center of the Sun crosses `altitude` (in degrees) before and after the
+    /// transit at Julian Day `transit`.
+    fn rise_set_julian_day(
+        &self,
+        transit: f64,
+        altitude: f64,
+    ) -> Result<RiseSet, AnnualSolarEventError> {
+        let (_, declination) = self.hour_angle_and_declination(transit);
+        match self.cos_hour_angle(altitude, declination) {
+            cos_hour_angle if cos_hour_angle < -1.0 => return Ok(RiseSet::AlwaysAbove),
+            cos_hour_angle if cos_hour_angle > 1.0 => return Ok(RiseSet::AlwaysBelow),
+            _ => {}
+        }
+
+        let solve = |sign: f64| {
+            let mut julian_day = transit;
+            for _ in 0..MAX_ITERATIONS {
+                let (hour_angle, declination) = self.hour_angle_and_declination(julian_day);
+                let target = sign
+                    * self
+                        .cos_hour_angle(altitude, declination)
+                        .clamp(-1.0, 1.0)
+                        .acos()
+                        .to_degrees();
+                let correction = ((target - hour_angle + 180.0).rem_euclid(360.0) - 180.0) / 360.0;
+                julian_day += correction;
+                if correction.abs() < CONVERGENCE {
+                    break;
+                }
+            }
+            DateTime::<Utc>::from_julian_day(julian_day)
+        };
+        Ok(RiseSet::RisesAndSets {
+            rise: solve(-1.0)?,
+            set: solve(1.0)?,
+        })
+    }
+
+    /// Returns the cosine of the hour angle at which the center of the Sun, at `declination`, is
+    /// at `altitude` (Meeus, equation 15.1). It is below -1 if the Sun stays above the altitude,
+    /// and above 1 if it stays below it.
+    fn cos_hour_angle(&self, altitude: f64, declination: f64) -> f64 {
+        let (latitude, declination) = (self.latitude.to_radians(), declination.to_radians());
+        (altitude.to_radians().sin() - latitude.sin() * declination.sin())
+            / (latitude.cos() * declination.cos())
+    }
+
+    /// Returns the local hour angle of the Sun (-180° to 180°, positive west of the meridian) and
+    /// its apparent declination, in degrees, at Julian Day `julian_day` in Universal Time.
+    fn hour_angle_and_declination(&self, julian_day: f64) -> (f64, f64) {
+        let sun = SunPosition::from_julian_ephemeris_day(delta_t::julian_ephemeris_day(julian_day));
+        let hour_angle =
+            (apparent_sidereal_time(julian_day) + self.longitude - sun.right_ascension() + 180.0)
+                .rem_euclid(360.0)
+                - 180.0;
+        (hour_angle, sun.declination())
+    }
+}
+
+/// Returns the apparent sidereal time at Greenwich, in degrees (0–360), at Julian Day
+/// `julian_day` in Universal Time (Meeus, equation 12.4 and the nutation in right ascension).
+fn apparent_sidereal_time(julian_day: f64) -> f64 {
+    let t = (julian_day - J2000) / DAYS_PER_JULIAN_CENTURY;
+    let mean = 280.460_618_37
+        + 360.985_647_366_29 * (julian_day - J2000)
+        + t * t * (0.000_387_933 - t / 38_710_000.0);
+
+    let t = (delta_t::julian_ephemeris_day(julian_day) - J2000) / DAYS_PER_JULIAN_CENTURY;
+    let (delta_psi, delta_epsilon) = nutation::nutation(t);
+    let epsilon = (nutation::mean_obliquity(t) + delta_epsilon).to_radians();
+
+    (mean + delta_psi * epsilon.cos()).rem_euclid(360.0)
+}
@@ -0,0 +1,195 @@
+// Copyright (c) 2023-2024, Johan Thorén.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The periods of midnight sun and polar night around the solstices.
+
+use chrono::{DateTime, NaiveDate, TimeDelta, Utc};
+
+use crate::{
+    AnnualSolarEvent, AnnualSolarEventError, DecemberSolstice, JuneSolstice, Observer, RiseSet,
+};
+
+/// The most days a period of midnight sun or polar night can extend from its solstice. Even at
+/// the poles, the Sun crosses the horizon within a few days of the equinoxes.
+const MAX_DAYS_FROM_SOLSTICE: i64 = 110;
+
+/// Whether the Sun stays above or below the horizon during a polar period.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum PolarPeriodKind {
+    /// The Sun stays above the horizon all day.
+    MidnightSun,
+    /// The Sun stays below the horizon all day.
+    PolarNight,
+}
+
+/// A period of midnight sun or polar night around a solstice.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct PolarPeriod {
+    kind: PolarPeriodKind,
+    first_date: NaiveDate,
+    last_date: NaiveDate,
+    solstice: DateTime<Utc>,
+    solstice_date: NaiveDate,
+}
+
+impl PolarPeriod {
+    /// Returns whether the period is a midnight sun or a polar night.
+    pub fn kind(&self) -> PolarPeriodKind {
+        self.kind
+    }
+
+    /// Returns the first local date on which the Sun does not rise or set.
+    pub fn first_date(&self) -> NaiveDate {
+        self.first_date
+    }
+
+    /// Returns the last local date on which the Sun does not rise or set.
+    pub fn last_date(&self) -> NaiveDate {
+        self.last_date
+    }
+
+    /// Returns the number of days in the period, counting the first and last dates.
+    pub fn days(&self) -> i64 {
+        (self.last_date - self.first_date).num_days() + 1
+    }
+
+    /// Returns the instant of the solstice within the period.
+    pub fn solstice(&self) -> DateTime<Utc> {
+        self.solstice
+    }
+
+    /// Returns the number of days from the first date of the period to the local date of the
+    /// solstice.
+    pub fn days_before_solstice(&self) -> i64 {
+        (self.solstice_date - self.first_date).num_days()
+    }
+
+    /// Returns the number of days from the local date of the solstice to the last date of the
+    /// period, i.e. how many days remain after the solstice.
+    pub fn days_after_solstice(&self) -> i64 {
+        (self.last_date - self.solstice_date).num_days()
+    }
+}
+
+/// Contains the periods of midnight sun and polar night around the June and December solstices of
+/// a year for an observer.
+///
+/// Each period contains its solstice, so it may begin in the previous year or end in the next
+/// year. At latitudes below the polar circles, there are no periods.
+#[derive(Debug)]
+pub struct PolarPeriods {
+    year: i32,
+    june: Option<PolarPeriod>,
+    december: Option<PolarPeriod>,
+}
+
+impl PolarPeriods {
+    /// Creates a new `PolarPeriods` instance with the polar periods around the solstices of the
+    /// specified year, for an observer.
+    ///
+    /// The periods follow [`Observer::solar_day`], so they account for refraction, the size of
+    /// the Sun and the elevation of the observer, and are longer than the polar circles alone
+    /// suggest.
+    ///
+    /// Returns an error if the year is outside the valid range.
+    ///
+    /// # Example
+    /// ```
+    /// use solar_calendar_events::{Observer, PolarPeriodKind, PolarPeriods};
+    ///
+    /// let tromso = Observer::new(69.65, 18.96, 0.0);
+    /// let periods = PolarPeriods::for_year(&tromso, 2024).unwrap();
+    /// let midnight_sun = periods.midnight_sun().unwrap();
+    ///
+    /// assert_eq!(midnight_sun.kind(), PolarPeriodKind::MidnightSun);
+    /// assert_eq!(midnight_sun.first_date().to_string(), "2024-05-18");
+    /// assert_eq!(midnight_sun.days_after_solstice(), 34);
+    /// ```
+    pub fn for_year(observer: &Observer, year: i32) -> Result<Self, AnnualSolarEventError> {
+        Ok(Self {
+            year,
+            june: polar_period(observer, JuneSolstice::for_year(year)?.date_time())?,
+            december: polar_period(observer, DecemberSolstice::for_year(year)?.date_time())?,
+        })
+    }
+
+    /// Returns the polar period around the June Solstice, the midnight sun in the north and the
+    /// polar night in the south.
+    pub fn june(&self) -> Option<&PolarPeriod> {
+        self.june.as_ref()
+    }
+
+    /// Returns the polar period around the December Solstice, the polar night in the north and
+    /// the midnight sun in the south.
+    pub fn december(&self) -> Option<&PolarPeriod> {
+        self.december.as_ref()
+    }
+
+    /// Returns the period of midnight sun, if any.
+    pub fn midnight_sun(&self) -> Option<&PolarPeriod> {
+        self.iter()
+            .find(|period| period.kind == PolarPeriodKind::MidnightSun)
+    }
+
+    /// Returns the period of polar night, if any.
+    pub fn polar_night(&self) -> Option<&PolarPeriod> {
+        self.iter()
+            .find(|period| period.kind == PolarPeriodKind::PolarNight)
+    }
+
+    /// Returns an iterator over the polar periods in chronological order.
+    pub fn iter(&self) -> impl Iterator<Item = &PolarPeriod> {
+        self.june.iter().chain(self.december.iter())
+    }
+
+    /// Returns the year of the solstices.
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+}
+
+/// Finds the polar period around a solstice for an observer, or `None` if the Sun rises and sets
+/// on the date of the solstice.
+fn polar_period(
+    observer: &Observer,
+    solstice: DateTime<Utc>,
+) -> Result<Option<PolarPeriod>, AnnualSolarEventError> {
+    // The local mean date, four minutes of time per degree of longitude.
+    let solstice_date =
+        (solstice + TimeDelta::seconds((observer.longitude * 240.0).round() as i64)).date_naive();
+    let state = observer.solar_day(solstice_date)?.sunrise_sunset();
+    let kind = match state {
+        RiseSet::AlwaysAbove => PolarPeriodKind::MidnightSun,
+        RiseSet::AlwaysBelow => PolarPeriodKind::PolarNight,
+        RiseSet::RisesAndSets { .. } => return Ok(None),
+    };
+
+    // The declination of the Sun changes monotonically away from the solstice, so the dates with
+    // the same state form one interval. Find its ends by bisection over the number of days.
+    let last_day = |direction: i64| -> Result<i64, AnnualSolarEventError> {
+        let (mut inside, mut outside) = (0, MAX_DAYS_FROM_SOLSTICE);
+        while outside - inside > 1 {
+            let middle = (inside + outside) / 2;
+            let date = solstice_date + TimeDelta::days(direction * middle);
+            if observer.solar_day(date)?.sunrise_sunset() == state {
+                inside = middle;
+            } else {
+                outside = middle;
+            }
+        }
+        Ok(inside)
+    };
+
+    Ok(Some(PolarPeriod {
+        kind,
+        first_date: solstice_date - TimeDelta::days(last_day(-1)?),
+        last_date: solstice_date + TimeDelta::days(last_day(1)?),
+        solstice,
+        solstice_date,
+    }))
+}
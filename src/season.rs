@@ -0,0 +1,320 @@
+// Copyright (c) 2023-2024, Johan Thorén.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The astronomical seasons, which run from one equinox or solstice to the next.
+
+use std::fmt;
+use std::str::FromStr;
+
+use chrono::{DateTime, TimeDelta, Utc};
+
+use crate::solar_event::normalize_name;
+use crate::{
+    next_solar_event_after, previous_solar_event_before, AnnualSolarEventError, CalculationMethod,
+    SolarEvent, SolarEventKind,
+};
+
+/// A hemisphere of the Earth, which determines the names of the seasons.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Hemisphere {
+    /// The Northern Hemisphere, where summer begins at the June Solstice.
+    Northern,
+    /// The Southern Hemisphere, where summer begins at the December Solstice.
+    Southern,
+}
+
+impl Hemisphere {
+    /// Returns the hemisphere of a latitude in degrees, counting the equator as northern.
+    pub fn from_latitude(latitude: f64) -> Self {
+        if latitude < 0.0 {
+            Hemisphere::Southern
+        } else {
+            Hemisphere::Northern
+        }
+    }
+
+    /// Returns the kind of event that has the seasonal name in this hemisphere that `kind` has in
+    /// the north: `kind` itself in the north, and the event half a year away in the south.
+    ///
+    /// Shifting twice gives back `kind`, so this also maps an event in this hemisphere to the
+    /// event with the same seasonal name in the north. It is the only place where the seasons of
+    /// the two hemispheres are related.
+    pub(crate) fn shift(&self, kind: SolarEventKind) -> SolarEventKind {
+        match self {
+            Hemisphere::Northern => kind,
+            Hemisphere::Southern => match kind {
+                SolarEventKind::MarchEquinox => SolarEventKind::SeptemberEquinox,
+                SolarEventKind::JuneSolstice => SolarEventKind::DecemberSolstice,
+                SolarEventKind::SeptemberEquinox => SolarEventKind::MarchEquinox,
+                SolarEventKind::DecemberSolstice => SolarEventKind::JuneSolstice,
+            },
+        }
+    }
+}
+
+/// One of the four astronomical seasons.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum SeasonKind {
+    /// Spring, from the vernal equinox to the summer solstice.
+    Spring,
+    /// Summer, from the summer solstice to the autumnal equinox.
+    Summer,
+    /// Autumn, from the autumnal equinox to the winter solstice.
+    Autumn,
+    /// Winter, from the winter solstice to the vernal equinox.
+    Winter,
+}
+
+impl SeasonKind {
+    /// Returns the season that begins with the given kind of solar event in a hemisphere.
+    pub fn starting_with(kind: SolarEventKind, hemisphere: Hemisphere) -> Self {
+        kind.seasonal_event(hemisphere).season_start()
+    }
+
+    /// Returns the name of the season in English.
+    pub fn name(&self) -> &'static str {
+        match self {
+            SeasonKind::Spring => "Spring",
+            SeasonKind::Summer => "Summer",
+            SeasonKind::Autumn => "Autumn",
+            SeasonKind::Winter => "Winter",
+        }
+    }
+}
+
+impl fmt::Display for SeasonKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// An equinox or solstice by its seasonal name, which refers to a different event in each
+/// hemisphere.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum SeasonalEvent {
+    /// The spring, or vernal, equinox: the March Equinox in the north and the September Equinox
+    /// in the south.
+    SpringEquinox,
+    /// The summer solstice: the June Solstice in the north and the December Solstice in the south.
+    SummerSolstice,
+    /// The autumn, or autumnal, equinox: the September Equinox in the north and the March Equinox
+    /// in the south.
+    AutumnEquinox,
+    /// The winter solstice: the December Solstice in the north and the June Solstice in the south.
+    WinterSolstice,
+}
+
+impl SeasonalEvent {
+    /// All four seasonal events, in the order of the seasons they start.
+    pub const ALL: [SeasonalEvent; 4] = [
+        SeasonalEvent::SpringEquinox,
+        SeasonalEvent::SummerSolstice,
+        SeasonalEvent::AutumnEquinox,
+        SeasonalEvent::WinterSolstice,
+    ];
+
+    /// Returns the kind of solar event with this seasonal name in a hemisphere.
+    ///
+    /// # Example
+    /// ```
+    /// use solar_calendar_events::{Hemisphere, SeasonalEvent, SolarEventKind};
+    ///
+    /// let event: SeasonalEvent = "vernal equinox".parse().unwrap();
+    ///
+    /// assert_eq!(event.kind(Hemisphere::Northern), SolarEventKind::MarchEquinox);
+    /// assert_eq!(event.kind(Hemisphere::Southern), SolarEventKind::SeptemberEquinox);
+    /// ```
+    pub fn kind(&self, hemisphere: Hemisphere) -> SolarEventKind {
+        let northern = match self {
+            SeasonalEvent::SpringEquinox => SolarEventKind::MarchEquinox,
+            SeasonalEvent::SummerSolstice => SolarEventKind::JuneSolstice,
+            SeasonalEvent::AutumnEquinox => SolarEventKind::SeptemberEquinox,
+            SeasonalEvent::WinterSolstice => SolarEventKind::DecemberSolstice,
+        };
+        hemisphere.shift(northern)
+    }
+
+    /// Returns the season that the event starts.
+    pub fn season_start(&self) -> SeasonKind {
+        match self {
+            SeasonalEvent::SpringEquinox => SeasonKind::Spring,
+            SeasonalEvent::SummerSolstice => SeasonKind::Summer,
+            SeasonalEvent::AutumnEquinox => SeasonKind::Autumn,
+            SeasonalEvent::WinterSolstice => SeasonKind::Winter,
+        }
+    }
+
+    /// Creates the solar event with this seasonal name in a hemisphere for the specified year,
+    /// using the default calculation method.
+    ///
+    /// Which of the four event structs has this name depends on the hemisphere, which is only
+    /// known at runtime, so the event is returned as a [`SolarEvent`]. It has the same instant and
+    /// methods as the struct, and its `kind` tells which struct it corresponds to.
+    ///
+    /// Returns an error if the year is outside the valid range.
+    pub fn for_year(
+        &self,
+        hemisphere: Hemisphere,
+        year: i32,
+    ) -> Result<SolarEvent, AnnualSolarEventError> {
+        self.for_year_with_method(hemisphere, year, CalculationMethod::default())
+    }
+
+    /// Creates the solar event with this seasonal name in a hemisphere for the specified year,
+    /// using the given calculation method.
+    ///
+    /// Returns an error if the year is outside the range supported by `method`.
+    pub fn for_year_with_method(
+        &self,
+        hemisphere: Hemisphere,
+        year: i32,
+        method: CalculationMethod,
+    ) -> Result<SolarEvent, AnnualSolarEventError> {
+        SolarEvent::for_year_with_method(self.kind(hemisphere), year, method)
+    }
+
+    /// Returns the name of the event in English.
+    pub fn name(&self) -> &'static str {
+        match self {
+            SeasonalEvent::SpringEquinox => "Spring Equinox",
+            SeasonalEvent::SummerSolstice => "Summer Solstice",
+            SeasonalEvent::AutumnEquinox => "Autumn Equinox",
+            SeasonalEvent::WinterSolstice => "Winter Solstice",
+        }
+    }
+}
+
+impl fmt::Display for SeasonalEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for SeasonalEvent {
+    type Err = AnnualSolarEventError;
+
+    /// Parses the seasonal name of an event, ignoring case, spaces, underscores and hyphens. The
+    /// names "Vernal Equinox", "Autumnal Equinox" and "Fall Equinox" are also accepted.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match normalize_name(s).as_str() {
+            "springequinox" | "vernalequinox" => Ok(SeasonalEvent::SpringEquinox),
+            "summersolstice" => Ok(SeasonalEvent::SummerSolstice),
+            "autumnequinox" | "autumnalequinox" | "fallequinox" => Ok(SeasonalEvent::AutumnEquinox),
+            "wintersolstice" => Ok(SeasonalEvent::WinterSolstice),
+            _ => Err(AnnualSolarEventError::UnknownSolarEventKind(s.to_string())),
+        }
+    }
+}
+
+/// The astronomical season at an instant, with the equinox or solstice at each end and how far
+/// the instant is through it.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Season {
+    kind: SeasonKind,
+    hemisphere: Hemisphere,
+    date_time: DateTime<Utc>,
+    start: SolarEvent,
+    end: SolarEvent,
+}
+
+impl Season {
+    /// Finds the astronomical season at an instant in a hemisphere, using the default calculation
+    /// method.
+    ///
+    /// A season starts at the instant of its equinox or solstice and ends at the next one, so
+    /// winter spans the end of the year.
+    ///
+    /// # Errors
+    /// Returns an error if the start or end of the season is in a year outside the valid range.
+    ///
+    /// # Example
+    /// ```
+    /// use chrono::{DateTime, Utc};
+    /// use solar_calendar_events::{Hemisphere, Season, SeasonKind, SolarEventKind};
+    ///
+    /// let date_time: DateTime<Utc> = "2025-01-15T00:00:00Z".parse().unwrap();
+    /// let season = Season::at(date_time, Hemisphere::Northern).unwrap();
+    ///
+    /// assert_eq!(season.kind(), SeasonKind::Winter);
+    /// assert_eq!(season.start().kind(), SolarEventKind::DecemberSolstice);
+    /// assert_eq!(season.start().year(), 2024);
+    /// assert_eq!(season.end().year(), 2025);
+    /// assert_eq!((season.fraction_elapsed() * 100.0).round(), 28.0);
+    ///
+    /// let season = Season::at(date_time, Hemisphere::Southern).unwrap();
+    ///
+    /// assert_eq!(season.kind(), SeasonKind::Summer);
+    /// ```
+    pub fn at(
+        date_time: DateTime<Utc>,
+        hemisphere: Hemisphere,
+    ) -> Result<Self, AnnualSolarEventError> {
+        // The start is the last event before the end, so that it may equal `date_time`.
+        let (end, _) = next_solar_event_after(date_time, None)?;
+        let (start, _) = previous_solar_event_before(end.date_time(), None)?;
+        Ok(Self {
+            kind: SeasonKind::starting_with(start.kind(), hemisphere),
+            hemisphere,
+            date_time,
+            start,
+            end,
+        })
+    }
+
+    /// Returns the kind of the season.
+    pub fn kind(&self) -> SeasonKind {
+        self.kind
+    }
+
+    /// Returns the hemisphere the season is named for.
+    pub fn hemisphere(&self) -> Hemisphere {
+        self.hemisphere
+    }
+
+    /// Returns the instant the season was found for.
+    pub fn date_time(&self) -> DateTime<Utc> {
+        self.date_time
+    }
+
+    /// Returns the equinox or solstice that starts the season.
+    ///
+    /// Which of the four event structs starts the season depends on the instant and the
+    /// hemisphere, so the event is returned as a [`SolarEvent`] rather than as the struct. Its
+    /// `kind` tells which struct it corresponds to, and it has the same instant.
+    pub fn start(&self) -> SolarEvent {
+        self.start
+    }
+
+    /// Returns the equinox or solstice that ends the season and starts the next one.
+    ///
+    /// Like `start`, the event is returned as a [`SolarEvent`].
+    pub fn end(&self) -> SolarEvent {
+        self.end
+    }
+
+    /// Returns the length of the season.
+    pub fn duration(&self) -> TimeDelta {
+        self.end.date_time() - self.start.date_time()
+    }
+
+    /// Returns the time from the start of the season to the instant.
+    pub fn time_elapsed(&self) -> TimeDelta {
+        self.date_time - self.start.date_time()
+    }
+
+    /// Returns the time from the instant to the end of the season.
+    pub fn time_remaining(&self) -> TimeDelta {
+        self.end.date_time() - self.date_time
+    }
+
+    /// Returns the fraction of the season that has elapsed at the instant, from 0.0 at the start
+    /// towards 1.0 at the end.
+    pub fn fraction_elapsed(&self) -> f64 {
+        self.time_elapsed().as_seconds_f64() / self.duration().as_seconds_f64()
+    }
+}
@@ -0,0 +1,463 @@
+// Copyright (c) 2023-2024, Johan Thorén.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! A single value type for the four equinoxes and solstices, to select and loop over them at
+//! runtime.
+
+use std::fmt;
+use std::iter::FusedIterator;
+use std::str::FromStr;
+
+use chrono::{DateTime, Datelike, TimeDelta, Utc};
+
+use crate::{
+    AnnualSolarEvent, AnnualSolarEventError, CalculationMethod, DecemberSolstice, Hemisphere,
+    HistoricalDate, JuneSolstice, MarchEquinox, SeasonKind, SeasonalEvent, SeptemberEquinox,
+    SunPosition,
+};
+
+/// One of the four equinoxes and solstices, in the order they occur in a year.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum SolarEventKind {
+    /// The March Equinox, when the apparent longitude of the Sun is 0°.
+    MarchEquinox,
+    /// The June Solstice, when the apparent longitude of the Sun is 90°.
+    JuneSolstice,
+    /// The September Equinox, when the apparent longitude of the Sun is 180°.
+    SeptemberEquinox,
+    /// The December Solstice, when the apparent longitude of the Sun is 270°.
+    DecemberSolstice,
+}
+
+impl SolarEventKind {
+    /// All four kinds, in the order they occur in a year.
+    pub const ALL: [SolarEventKind; 4] = [
+        SolarEventKind::MarchEquinox,
+        SolarEventKind::JuneSolstice,
+        SolarEventKind::SeptemberEquinox,
+        SolarEventKind::DecemberSolstice,
+    ];
+
+    /// Returns the apparent longitude of the Sun at the event, in degrees.
+    pub fn solar_longitude(&self) -> f64 {
+        90.0 * *self as u8 as f64
+    }
+
+    /// Returns the seasonal name of the event in a hemisphere, such as the June Solstice being the
+    /// summer solstice in the north and the winter solstice in the south.
+    pub fn seasonal_event(&self, hemisphere: Hemisphere) -> SeasonalEvent {
+        match hemisphere.shift(*self) {
+            SolarEventKind::MarchEquinox => SeasonalEvent::SpringEquinox,
+            SolarEventKind::JuneSolstice => SeasonalEvent::SummerSolstice,
+            SolarEventKind::SeptemberEquinox => SeasonalEvent::AutumnEquinox,
+            SolarEventKind::DecemberSolstice => SeasonalEvent::WinterSolstice,
+        }
+    }
+
+    /// Returns the season that the event starts in a hemisphere.
+    pub fn season_start(&self, hemisphere: Hemisphere) -> SeasonKind {
+        self.seasonal_event(hemisphere).season_start()
+    }
+
+    /// Returns the name of the event in English.
+    pub fn name(&self) -> &'static str {
+        match self {
+            SolarEventKind::MarchEquinox => "March Equinox",
+            SolarEventKind::JuneSolstice => "June Solstice",
+            SolarEventKind::SeptemberEquinox => "September Equinox",
+            SolarEventKind::DecemberSolstice => "December Solstice",
+        }
+    }
+}
+
+impl fmt::Display for SolarEventKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for SolarEventKind {
+    type Err = AnnualSolarEventError;
+
+    /// Parses the name of an event, ignoring case, spaces, underscores and hyphens, so that
+    /// `"March Equinox"`, `"march_equinox"` and `"MarchEquinox"` are all accepted.
+    ///
+    /// # Example
+    /// ```
+    /// use solar_calendar_events::SolarEventKind;
+    ///
+    /// let kind: SolarEventKind = "june-solstice".parse().unwrap();
+    ///
+    /// assert_eq!(kind, SolarEventKind::JuneSolstice);
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        SolarEventKind::ALL
+            .into_iter()
+            .find(|kind| normalize_name(kind.name()) == normalize_name(s))
+            .ok_or_else(|| AnnualSolarEventError::UnknownSolarEventKind(s.to_string()))
+    }
+}
+
+/// Lowercases a name and removes spaces, underscores and hyphens, for comparing names as they are
+/// written in configuration files and user input.
+pub(crate) fn normalize_name(name: &str) -> String {
+    name.chars()
+        .filter(|c| !matches!(c, ' ' | '_' | '-'))
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// The instant of an equinox or solstice, of any kind.
+///
+/// This carries the same values as [`MarchEquinox`], [`JuneSolstice`], [`SeptemberEquinox`] and
+/// [`DecemberSolstice`], together with its kind.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SolarEvent {
+    kind: SolarEventKind,
+    julian_day: f64,
+    julian_ephemeris_day: f64,
+    date_time: DateTime<Utc>,
+}
+
+impl SolarEvent {
+    /// Creates the solar event of the given kind for the specified year, using the default
+    /// calculation method.
+    ///
+    /// Returns an error if the year is outside the valid range.
+    ///
+    /// # Example
+    /// ```
+    /// use solar_calendar_events::{SolarEvent, SolarEventKind};
+    ///
+    /// let kind = "September Equinox".parse().unwrap();
+    /// let event = SolarEvent::for_year(kind, 2024).unwrap();
+    ///
+    /// assert_eq!(event.kind(), SolarEventKind::SeptemberEquinox);
+    /// assert_eq!(event.date_time().format("%F").to_string(), "2024-09-22");
+    /// ```
+    pub fn for_year(kind: SolarEventKind, year: i32) -> Result<Self, AnnualSolarEventError> {
+        Self::for_year_with_method(kind, year, CalculationMethod::default())
+    }
+
+    /// Creates the solar event of the given kind for the specified year, using the given
+    /// calculation method.
+    ///
+    /// Returns an error if the year is outside the range supported by `method`.
+    pub fn for_year_with_method(
+        kind: SolarEventKind,
+        year: i32,
+        method: CalculationMethod,
+    ) -> Result<Self, AnnualSolarEventError> {
+        Ok(match kind {
+            SolarEventKind::MarchEquinox => {
+                Self::from(&MarchEquinox::for_year_with_method(year, method)?)
+            }
+            SolarEventKind::JuneSolstice => {
+                Self::from(&JuneSolstice::for_year_with_method(year, method)?)
+            }
+            SolarEventKind::SeptemberEquinox => {
+                Self::from(&SeptemberEquinox::for_year_with_method(year, method)?)
+            }
+            SolarEventKind::DecemberSolstice => {
+                Self::from(&DecemberSolstice::for_year_with_method(year, method)?)
+            }
+        })
+    }
+
+    /// Creates a solar event of the given kind from an event struct.
+    fn new<T: AnnualSolarEvent>(kind: SolarEventKind, event: &T) -> Self {
+        Self {
+            kind,
+            julian_day: event.julian_day(),
+            julian_ephemeris_day: event.julian_ephemeris_day(),
+            date_time: event.date_time(),
+        }
+    }
+
+    /// Returns the kind of the solar event.
+    pub fn kind(&self) -> SolarEventKind {
+        self.kind
+    }
+
+    /// Returns the date and time of the solar event as a `DateTime<Utc>`.
+    pub fn date_time(&self) -> DateTime<Utc> {
+        self.date_time
+    }
+
+    /// Returns the date of the solar event in the calendar in use at the time, as
+    /// [`AnnualSolarEvent::historical_date`] does.
+    pub fn historical_date(&self) -> Result<HistoricalDate, AnnualSolarEventError> {
+        HistoricalDate::from_julian_day(self.julian_day)
+    }
+
+    /// Returns the Julian Day Number of the solar event in Universal Time.
+    pub fn julian_day(&self) -> f64 {
+        self.julian_day
+    }
+
+    /// Returns the Julian Ephemeris Day of the solar event, i.e. the Julian Day in Terrestrial
+    /// (Dynamical) Time.
+    pub fn julian_ephemeris_day(&self) -> f64 {
+        self.julian_ephemeris_day
+    }
+
+    /// Returns the year of the solar event.
+    pub fn year(&self) -> i32 {
+        self.date_time.year()
+    }
+
+    /// Returns the apparent position of the Sun at the instant of the solar event.
+    pub fn sun_position(&self) -> SunPosition {
+        SunPosition::from_julian_ephemeris_day(self.julian_ephemeris_day)
+    }
+}
+
+impl From<&MarchEquinox> for SolarEvent {
+    fn from(event: &MarchEquinox) -> Self {
+        Self::new(SolarEventKind::MarchEquinox, event)
+    }
+}
+
+impl From<&JuneSolstice> for SolarEvent {
+    fn from(event: &JuneSolstice) -> Self {
+        Self::new(SolarEventKind::JuneSolstice, event)
+    }
+}
+
+impl From<&SeptemberEquinox> for SolarEvent {
+    fn from(event: &SeptemberEquinox) -> Self {
+        Self::new(SolarEventKind::SeptemberEquinox, event)
+    }
+}
+
+impl From<&DecemberSolstice> for SolarEvent {
+    fn from(event: &DecemberSolstice) -> Self {
+        Self::new(SolarEventKind::DecemberSolstice, event)
+    }
+}
+
+/// Finds the first equinox or solstice after an instant, optionally only of the given kind, using
+/// the default calculation method.
+///
+/// Returns the event and the time from `date_time` until it. The search continues into the next
+/// year, so after the December Solstice it finds the March Equinox of the following year.
+///
+/// # Errors
+/// Returns an error if the event would fall in a year outside the valid range.
+///
+/// # Example
+/// ```
+/// use chrono::{DateTime, Utc};
+/// use solar_calendar_events::{next_solar_event_after, SolarEventKind};
+///
+/// let date_time: DateTime<Utc> = "2024-12-25T00:00:00Z".parse().unwrap();
+/// let (event, until) = next_solar_event_after(date_time, None).unwrap();
+///
+/// assert_eq!(event.kind(), SolarEventKind::MarchEquinox);
+/// assert_eq!(event.year(), 2025);
+/// assert_eq!(until.num_days(), 85);
+///
+/// let kind = Some(SolarEventKind::JuneSolstice);
+/// let (event, _) = next_solar_event_after(date_time, kind).unwrap();
+///
+/// assert_eq!(event.date_time().format("%F").to_string(), "2025-06-21");
+/// ```
+pub fn next_solar_event_after(
+    date_time: DateTime<Utc>,
+    kind: Option<SolarEventKind>,
+) -> Result<(SolarEvent, TimeDelta), AnnualSolarEventError> {
+    for year in [date_time.year(), date_time.year() + 1] {
+        for event_kind in kinds(kind) {
+            let event = SolarEvent::for_year(event_kind, year)?;
+            if event.date_time > date_time {
+                return Ok((event, event.date_time - date_time));
+            }
+        }
+    }
+    unreachable!("every kind of event occurs once a year")
+}
+
+/// Finds the last equinox or solstice before an instant, optionally only of the given kind, using
+/// the default calculation method.
+///
+/// Returns the event and the time from it until `date_time`. The search continues into the
+/// previous year, so before the March Equinox it finds the December Solstice of the year before.
+///
+/// # Errors
+/// Returns an error if the event would fall in a year outside the valid range.
+///
+/// # Example
+/// ```
+/// use chrono::{DateTime, Utc};
+/// use solar_calendar_events::{previous_solar_event_before, SolarEventKind};
+///
+/// let date_time: DateTime<Utc> = "2024-01-10T00:00:00Z".parse().unwrap();
+/// let (event, since) = previous_solar_event_before(date_time, None).unwrap();
+///
+/// assert_eq!(event.kind(), SolarEventKind::DecemberSolstice);
+/// assert_eq!(event.year(), 2023);
+/// assert_eq!(since.num_days(), 18);
+/// ```
+pub fn previous_solar_event_before(
+    date_time: DateTime<Utc>,
+    kind: Option<SolarEventKind>,
+) -> Result<(SolarEvent, TimeDelta), AnnualSolarEventError> {
+    for year in [date_time.year(), date_time.year() - 1] {
+        for event_kind in kinds(kind).rev() {
+            let event = SolarEvent::for_year(event_kind, year)?;
+            if event.date_time < date_time {
+                return Ok((event, date_time - event.date_time));
+            }
+        }
+    }
+    unreachable!("every kind of event occurs once a year")
+}
+
+/// Returns the kinds to search in chronological order: only `kind` if given, or all four.
+fn kinds(kind: Option<SolarEventKind>) -> impl DoubleEndedIterator<Item = SolarEventKind> {
+    SolarEventKind::ALL
+        .into_iter()
+        .filter(move |event_kind| kind.is_none_or(|kind| kind == *event_kind))
+}
+
+/// An iterator over the four equinoxes and solstices of a year in chronological order.
+pub type SolarEventsIter = std::array::IntoIter<SolarEvent, 4>;
+
+/// A lazy iterator over the equinoxes and solstices between two instants, in chronological order,
+/// across year boundaries.
+///
+/// Each event is calculated as the iterator reaches it, from either end.
+#[derive(Clone, Debug)]
+pub struct SolarEventIter {
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+    method: CalculationMethod,
+    // Events are numbered four per year, from the March Equinox; `front` is the next event from
+    // the start and `back` is one past the next event from the end.
+    front: i64,
+    back: i64,
+}
+
+impl SolarEventIter {
+    /// Creates an iterator over the equinoxes and solstices from `start` (inclusive) to `end`
+    /// (exclusive), using the default calculation method. The iterator is empty if `end` is not
+    /// after `start`.
+    ///
+    /// Returns an error if either instant is in a year outside the valid range.
+    ///
+    /// # Example
+    /// ```
+    /// use chrono::{DateTime, Utc};
+    /// use solar_calendar_events::{SolarEventIter, SolarEventKind};
+    ///
+    /// let start: DateTime<Utc> = "2023-10-01T00:00:00Z".parse().unwrap();
+    /// let end: DateTime<Utc> = "2024-10-01T00:00:00Z".parse().unwrap();
+    /// let events = SolarEventIter::between(start, end).unwrap();
+    /// let kinds: Vec<SolarEventKind> = events.rev().map(|event| event.kind()).collect();
+    ///
+    /// assert_eq!(
+    ///     kinds,
+    ///     [
+    ///         SolarEventKind::SeptemberEquinox,
+    ///         SolarEventKind::JuneSolstice,
+    ///         SolarEventKind::MarchEquinox,
+    ///         SolarEventKind::DecemberSolstice,
+    ///     ]
+    /// );
+    /// ```
+    pub fn between(
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<Self, AnnualSolarEventError> {
+        Self::between_with_method(start, end, CalculationMethod::default())
+    }
+
+    /// Creates an iterator over the equinoxes and solstices from `start` (inclusive) to `end`
+    /// (exclusive), using the given calculation method.
+    ///
+    /// Returns an error if either instant is in a year outside the range supported by `method`.
+    pub fn between_with_method(
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        method: CalculationMethod,
+    ) -> Result<Self, AnnualSolarEventError> {
+        for year in [start.year(), end.year()] {
+            if !method.year_range().contains(&year) {
+                return Err(AnnualSolarEventError::YearOutOfRange(year));
+            }
+        }
+        let front = 4 * start.year() as i64;
+        let back = if end > start {
+            4 * (end.year() as i64 + 1)
+        } else {
+            front
+        };
+        Ok(Self {
+            start,
+            end,
+            method,
+            front,
+            back,
+        })
+    }
+
+    /// Calculates the event with the given number.
+    ///
+    /// `between_with_method` only numbers events in years within the range of the method, so the
+    /// calculation cannot fail.
+    fn event(&self, index: i64) -> SolarEvent {
+        let year = index.div_euclid(4) as i32;
+        let kind = SolarEventKind::ALL[index.rem_euclid(4) as usize];
+        debug_assert!(
+            self.method.year_range().contains(&year),
+            "the year {} is outside the range of {:?}",
+            year,
+            self.method
+        );
+        SolarEvent::for_year_with_method(kind, year, self.method)
+            .expect("the years between start and end are within the range of the method")
+    }
+}
+
+impl Iterator for SolarEventIter {
+    type Item = SolarEvent;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.front < self.back {
+            let event = self.event(self.front);
+            self.front += 1;
+            if event.date_time >= self.end {
+                self.front = self.back;
+            } else if event.date_time >= self.start {
+                return Some(event);
+            }
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some((self.back - self.front) as usize))
+    }
+}
+
+impl DoubleEndedIterator for SolarEventIter {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        while self.front < self.back {
+            self.back -= 1;
+            let event = self.event(self.back);
+            if event.date_time < self.start {
+                self.back = self.front;
+            } else if event.date_time < self.end {
+                return Some(event);
+            }
+        }
+        None
+    }
+}
+
+impl FusedIterator for SolarEventIter {}
@@ -0,0 +1,345 @@
+// Copyright (c) 2023-2024, Johan Thorén.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The 24 solar terms (jieqi) of the Chinese calendar, when the apparent longitude of the Sun is
+//! a multiple of 15°.
+
+use std::iter::Chain;
+use std::slice;
+
+use chrono::{DateTime, FixedOffset, NaiveDate, Utc};
+
+use crate::{
+    delta_t, vsop87, AnnualSolarEvent, AnnualSolarEventError, CalculationMethod, DecemberSolstice,
+    JulianDateTimeUtc, JuneSolstice, MarchEquinox, SeptemberEquinox,
+};
+
+/// The offset of China Standard Time from UTC, in seconds.
+const CHINA_STANDARD_TIME_OFFSET: i32 = 8 * 3_600;
+
+/// One of the 24 solar terms, in their traditional order starting with Lichun.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum SolarTerm {
+    /// Start of Spring (立春), 315°.
+    Lichun,
+    /// Rain Water (雨水), 330°.
+    Yushui,
+    /// Awakening of Insects (惊蛰), 345°.
+    Jingzhe,
+    /// Spring Equinox (春分), 0°.
+    Chunfen,
+    /// Pure Brightness (清明), 15°.
+    Qingming,
+    /// Grain Rain (谷雨), 30°.
+    Guyu,
+    /// Start of Summer (立夏), 45°.
+    Lixia,
+    /// Grain Buds (小满), 60°.
+    Xiaoman,
+    /// Grain in Ear (芒种), 75°.
+    Mangzhong,
+    /// Summer Solstice (夏至), 90°.
+    Xiazhi,
+    /// Minor Heat (小暑), 105°.
+    Xiaoshu,
+    /// Major Heat (大暑), 120°.
+    Dashu,
+    /// Start of Autumn (立秋), 135°.
+    Liqiu,
+    /// End of Heat (处暑), 150°.
+    Chushu,
+    /// White Dew (白露), 165°.
+    Bailu,
+    /// Autumn Equinox (秋分), 180°.
+    Qiufen,
+    /// Cold Dew (寒露), 195°.
+    Hanlu,
+    /// Frost's Descent (霜降), 210°.
+    Shuangjiang,
+    /// Start of Winter (立冬), 225°.
+    Lidong,
+    /// Minor Snow (小雪), 240°.
+    Xiaoxue,
+    /// Major Snow (大雪), 255°.
+    Daxue,
+    /// Winter Solstice (冬至), 270°.
+    Dongzhi,
+    /// Minor Cold (小寒), 285°.
+    Xiaohan,
+    /// Major Cold (大寒), 300°.
+    Dahan,
+}
+
+impl SolarTerm {
+    /// All 24 solar terms, in their traditional order starting with Lichun.
+    pub const ALL: [SolarTerm; 24] = [
+        SolarTerm::Lichun,
+        SolarTerm::Yushui,
+        SolarTerm::Jingzhe,
+        SolarTerm::Chunfen,
+        SolarTerm::Qingming,
+        SolarTerm::Guyu,
+        SolarTerm::Lixia,
+        SolarTerm::Xiaoman,
+        SolarTerm::Mangzhong,
+        SolarTerm::Xiazhi,
+        SolarTerm::Xiaoshu,
+        SolarTerm::Dashu,
+        SolarTerm::Liqiu,
+        SolarTerm::Chushu,
+        SolarTerm::Bailu,
+        SolarTerm::Qiufen,
+        SolarTerm::Hanlu,
+        SolarTerm::Shuangjiang,
+        SolarTerm::Lidong,
+        SolarTerm::Xiaoxue,
+        SolarTerm::Daxue,
+        SolarTerm::Dongzhi,
+        SolarTerm::Xiaohan,
+        SolarTerm::Dahan,
+    ];
+
+    /// Returns the apparent longitude of the Sun at the start of the solar term, in degrees.
+    ///
+    /// # Example
+    /// ```
+    /// use solar_calendar_events::SolarTerm;
+    ///
+    /// assert_eq!(SolarTerm::Lichun.solar_longitude(), 315.0);
+    /// assert_eq!(SolarTerm::Chunfen.solar_longitude(), 0.0);
+    /// ```
+    pub fn solar_longitude(&self) -> f64 {
+        (315.0 + 15.0 * *self as u8 as f64) % 360.0
+    }
+
+    /// Returns the name of the solar term in pinyin, without tone marks.
+    pub fn name(&self) -> &'static str {
+        match self {
+            SolarTerm::Lichun => "Lichun",
+            SolarTerm::Yushui => "Yushui",
+            SolarTerm::Jingzhe => "Jingzhe",
+            SolarTerm::Chunfen => "Chunfen",
+            SolarTerm::Qingming => "Qingming",
+            SolarTerm::Guyu => "Guyu",
+            SolarTerm::Lixia => "Lixia",
+            SolarTerm::Xiaoman => "Xiaoman",
+            SolarTerm::Mangzhong => "Mangzhong",
+            SolarTerm::Xiazhi => "Xiazhi",
+            SolarTerm::Xiaoshu => "Xiaoshu",
+            SolarTerm::Dashu => "Dashu",
+            SolarTerm::Liqiu => "Liqiu",
+            SolarTerm::Chushu => "Chushu",
+            SolarTerm::Bailu => "Bailu",
+            SolarTerm::Qiufen => "Qiufen",
+            SolarTerm::Hanlu => "Hanlu",
+            SolarTerm::Shuangjiang => "Shuangjiang",
+            SolarTerm::Lidong => "Lidong",
+            SolarTerm::Xiaoxue => "Xiaoxue",
+            SolarTerm::Daxue => "Daxue",
+            SolarTerm::Dongzhi => "Dongzhi",
+            SolarTerm::Xiaohan => "Xiaohan",
+            SolarTerm::Dahan => "Dahan",
+        }
+    }
+
+    /// Returns the name of the solar term in simplified Chinese characters.
+    pub fn chinese_name(&self) -> &'static str {
+        match self {
+            SolarTerm::Lichun => "立春",
+            SolarTerm::Yushui => "雨水",
+            SolarTerm::Jingzhe => "惊蛰",
+            SolarTerm::Chunfen => "春分",
+            SolarTerm::Qingming => "清明",
+            SolarTerm::Guyu => "谷雨",
+            SolarTerm::Lixia => "立夏",
+            SolarTerm::Xiaoman => "小满",
+            SolarTerm::Mangzhong => "芒种",
+            SolarTerm::Xiazhi => "夏至",
+            SolarTerm::Xiaoshu => "小暑",
+            SolarTerm::Dashu => "大暑",
+            SolarTerm::Liqiu => "立秋",
+            SolarTerm::Chushu => "处暑",
+            SolarTerm::Bailu => "白露",
+            SolarTerm::Qiufen => "秋分",
+            SolarTerm::Hanlu => "寒露",
+            SolarTerm::Shuangjiang => "霜降",
+            SolarTerm::Lidong => "立冬",
+            SolarTerm::Xiaoxue => "小雪",
+            SolarTerm::Daxue => "大雪",
+            SolarTerm::Dongzhi => "冬至",
+            SolarTerm::Xiaohan => "小寒",
+            SolarTerm::Dahan => "大寒",
+        }
+    }
+
+    /// Calculates the Julian Ephemeris Day of the solar term in a given year.
+    ///
+    /// The equinoxes and solstices use the event structs, so that they match
+    /// `AnnualSolarEvents`. Meeus gives no tables for the other terms, so they are always solved
+    /// with VSOP87.
+    fn calculate_julian_day(
+        &self,
+        year: i32,
+        method: CalculationMethod,
+    ) -> Result<f64, AnnualSolarEventError> {
+        Ok(match self {
+            SolarTerm::Chunfen => {
+                MarchEquinox::for_year_with_method(year, method)?.julian_ephemeris_day()
+            }
+            SolarTerm::Xiazhi => {
+                JuneSolstice::for_year_with_method(year, method)?.julian_ephemeris_day()
+            }
+            SolarTerm::Qiufen => {
+                SeptemberEquinox::for_year_with_method(year, method)?.julian_ephemeris_day()
+            }
+            SolarTerm::Dongzhi => {
+                DecemberSolstice::for_year_with_method(year, method)?.julian_ephemeris_day()
+            }
+            _ => {
+                // No term is within a few days of January 1, so the term falls in the same year as
+                // the estimate.
+                let estimate = vsop87::estimate_solar_longitude(self.solar_longitude(), year);
+                vsop87::solve_solar_longitude(self.solar_longitude(), estimate)
+            }
+        })
+    }
+}
+
+/// The instant of a solar term in a specific year.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SolarTermEvent {
+    term: SolarTerm,
+    julian_day: f64,
+    julian_ephemeris_day: f64,
+    date_time: DateTime<Utc>,
+}
+
+impl SolarTermEvent {
+    /// Returns the solar term.
+    pub fn term(&self) -> SolarTerm {
+        self.term
+    }
+
+    /// Returns the date and time of the solar term as a `DateTime<Utc>`.
+    pub fn date_time(&self) -> DateTime<Utc> {
+        self.date_time
+    }
+
+    /// Returns the date and time of the solar term in China Standard Time (UTC+8).
+    pub fn china_standard_time(&self) -> DateTime<FixedOffset> {
+        self.date_time.with_timezone(
+            &FixedOffset::east_opt(CHINA_STANDARD_TIME_OFFSET)
+                .expect("China Standard Time is a valid offset"),
+        )
+    }
+
+    /// Returns the civil date of the solar term in China Standard Time, the date used by the
+    /// Chinese calendar.
+    pub fn civil_date(&self) -> NaiveDate {
+        self.china_standard_time().date_naive()
+    }
+
+    /// Returns the Julian Day Number of the solar term in Universal Time.
+    pub fn julian_day(&self) -> f64 {
+        self.julian_day
+    }
+
+    /// Returns the Julian Ephemeris Day of the solar term, i.e. the Julian Day in Terrestrial
+    /// (Dynamical) Time.
+    pub fn julian_ephemeris_day(&self) -> f64 {
+        self.julian_ephemeris_day
+    }
+}
+
+/// The index in `SolarTerm::ALL` of Xiaohan, the first solar term of a calendar year.
+const FIRST_TERM_OF_YEAR: usize = SolarTerm::Xiaohan as usize;
+
+/// Contains all 24 solar terms for a given year, from Xiaohan in January to Dongzhi in December.
+#[derive(Debug)]
+pub struct SolarTerms {
+    year: i32,
+    events: Vec<SolarTermEvent>,
+}
+
+impl SolarTerms {
+    /// Creates a new `SolarTerms` instance for the specified year, which contains all 24 solar
+    /// terms.
+    ///
+    /// Returns an error if the year is outside the valid range.
+    ///
+    /// # Example
+    /// ```
+    /// use solar_calendar_events::{SolarTerm, SolarTerms};
+    ///
+    /// let terms = SolarTerms::for_year(2024).unwrap();
+    ///
+    /// assert_eq!(terms.get(SolarTerm::Lichun).civil_date().to_string(), "2024-02-04");
+    /// ```
+    pub fn for_year(year: i32) -> Result<Self, AnnualSolarEventError> {
+        Self::for_year_with_method(year, CalculationMethod::default())
+    }
+
+    /// Creates a new `SolarTerms` instance for the specified year, using the given calculation
+    /// method for the equinoxes and solstices.
+    ///
+    /// Returns an error if the year is outside the range supported by `method`.
+    pub fn for_year_with_method(
+        year: i32,
+        method: CalculationMethod,
+    ) -> Result<Self, AnnualSolarEventError> {
+        if !method.year_range().contains(&year) {
+            return Err(AnnualSolarEventError::YearOutOfRange(year));
+        }
+        let events = SolarTerm::ALL
+            .iter()
+            .map(|term| {
+                let julian_ephemeris_day = term.calculate_julian_day(year, method)?;
+                let julian_day = delta_t::universal_julian_day(julian_ephemeris_day);
+                Ok(SolarTermEvent {
+                    term: *term,
+                    julian_day,
+                    julian_ephemeris_day,
+                    date_time: DateTime::<Utc>::from_julian_day(julian_day)?,
+                })
+            })
+            .collect::<Result<Vec<_>, AnnualSolarEventError>>()?;
+
+        Ok(Self { year, events })
+    }
+
+    /// Returns the solar term event of the given term.
+    pub fn get(&self, term: SolarTerm) -> &SolarTermEvent {
+        &self.events[term as usize]
+    }
+
+    /// Returns an iterator over the solar term events in chronological order, starting with
+    /// Xiaohan and Dahan in January.
+    pub fn iter(&self) -> SolarTermsIter<'_> {
+        self.events[FIRST_TERM_OF_YEAR..]
+            .iter()
+            .chain(&self.events[..FIRST_TERM_OF_YEAR])
+    }
+
+    /// Returns the year of these solar terms.
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+}
+
+/// An iterator over the solar term events of a year in chronological order.
+pub type SolarTermsIter<'a> =
+    Chain<slice::Iter<'a, SolarTermEvent>, slice::Iter<'a, SolarTermEvent>>;
+
+impl<'a> IntoIterator for &'a SolarTerms {
+    type Item = &'a SolarTermEvent;
+    type IntoIter = SolarTermsIter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
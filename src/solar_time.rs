@@ -0,0 +1,102 @@
+// Copyright (c) 2023-2024, Johan Thorén.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The equation of time, and conversions between UTC and local mean and apparent solar time.
+
+use chrono::{DateTime, NaiveDateTime, TimeDelta, Utc};
+
+use crate::{AnnualSolarEventError, SunPosition};
+
+/// Number of nanoseconds of time per degree of longitude (four minutes).
+const NANOSECONDS_PER_DEGREE: f64 = 240_000_000_000.0;
+
+/// The number of times the equation of time is re-evaluated when converting from apparent solar
+/// time. It changes by less than 30 seconds a day, so two evaluations are accurate to well below
+/// a millisecond.
+const APPARENT_TIME_ITERATIONS: usize = 2;
+
+/// Calculates the equation of time at an instant: apparent solar time minus mean solar time.
+///
+/// It is positive when a sundial is ahead of a clock keeping mean solar time, and ranges from
+/// about −14 minutes in February to about +16 minutes in November.
+///
+/// # Errors
+/// Returns an error if the year is outside the range supported by
+/// `CalculationMethod::TruncatedVsop87`.
+///
+/// # Example
+/// ```
+/// use chrono::{DateTime, TimeDelta, Utc};
+/// use solar_calendar_events::equation_of_time;
+///
+/// let date_time: DateTime<Utc> = "2024-11-03T12:00:00Z".parse().unwrap();
+/// let equation_of_time = equation_of_time(date_time).unwrap();
+///
+/// assert_eq!(equation_of_time.num_minutes(), 16);
+/// ```
+pub fn equation_of_time(date_time: DateTime<Utc>) -> Result<TimeDelta, AnnualSolarEventError> {
+    Ok(SunPosition::at(date_time)?.equation_of_time())
+}
+
+/// Converts an instant to local mean solar time at a longitude, in degrees positive east of
+/// Greenwich. Local mean time is ahead of UTC by four minutes per degree east.
+pub fn local_mean_time(date_time: DateTime<Utc>, longitude: f64) -> NaiveDateTime {
+    date_time.naive_utc() + longitude_offset(longitude)
+}
+
+/// Converts local mean solar time at a longitude, in degrees positive east of Greenwich, to UTC.
+pub fn utc_from_local_mean_time(local_mean_time: NaiveDateTime, longitude: f64) -> DateTime<Utc> {
+    (local_mean_time - longitude_offset(longitude)).and_utc()
+}
+
+/// Converts an instant to local apparent solar time at a longitude, in degrees positive east of
+/// Greenwich. This is the time shown by a sundial, with the Sun on the meridian at 12:00.
+///
+/// # Errors
+/// Returns an error if the year is outside the range supported by
+/// `CalculationMethod::TruncatedVsop87`.
+///
+/// # Example
+/// ```
+/// use chrono::{DateTime, Utc};
+/// use solar_calendar_events::local_apparent_time;
+///
+/// let date_time: DateTime<Utc> = "2024-02-11T12:00:00Z".parse().unwrap();
+/// let sundial = local_apparent_time(date_time, 0.0).unwrap();
+///
+/// assert_eq!(sundial.format("%H:%M").to_string(), "11:45");
+/// ```
+pub fn local_apparent_time(
+    date_time: DateTime<Utc>,
+    longitude: f64,
+) -> Result<NaiveDateTime, AnnualSolarEventError> {
+    Ok(local_mean_time(date_time, longitude) + equation_of_time(date_time)?)
+}
+
+/// Converts local apparent solar time at a longitude, in degrees positive east of Greenwich, to
+/// UTC.
+///
+/// # Errors
+/// Returns an error if the year is outside the range supported by
+/// `CalculationMethod::TruncatedVsop87`.
+pub fn utc_from_local_apparent_time(
+    local_apparent_time: NaiveDateTime,
+    longitude: f64,
+) -> Result<DateTime<Utc>, AnnualSolarEventError> {
+    let mean_utc = utc_from_local_mean_time(local_apparent_time, longitude);
+    let mut date_time = mean_utc;
+    for _ in 0..APPARENT_TIME_ITERATIONS {
+        date_time = mean_utc - equation_of_time(date_time)?;
+    }
+    Ok(date_time)
+}
+
+/// Returns the difference between local mean time at a longitude and UTC.
+fn longitude_offset(longitude: f64) -> TimeDelta {
+    TimeDelta::nanoseconds((longitude * NANOSECONDS_PER_DEGREE).round() as i64)
+}
@@ -0,0 +1,161 @@
+// Copyright (c) 2023-2024, Johan Thorén.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The apparent position of the Sun at any instant, following Meeus, *Astronomical Algorithms*
+//! (2nd ed.), chapters 25 and 28.
+
+use chrono::{DateTime, Datelike, TimeDelta, Utc};
+
+use crate::meeus::{DAYS_PER_JULIAN_CENTURY, J2000};
+use crate::{delta_t, nutation, vsop87, AnnualSolarEventError, JulianDateTimeUtc};
+
+/// Number of nanoseconds in a minute.
+const NANOSECONDS_PER_MINUTE: f64 = 60_000_000_000.0;
+
+/// The apparent geocentric position of the Sun at an instant.
+///
+/// The ecliptic coordinates are calculated from a truncated VSOP87 theory and referred to the
+/// true equinox of the date, corrected for nutation and aberration.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SunPosition {
+    longitude: f64,
+    latitude: f64,
+    distance: f64,
+    right_ascension: f64,
+    declination: f64,
+    equation_of_time: TimeDelta,
+}
+
+impl SunPosition {
+    /// Calculates the apparent position of the Sun at an instant.
+    ///
+    /// # Arguments
+    /// * `date_time` - The instant, converted to Terrestrial Time using `delta_t`.
+    ///
+    /// # Returns
+    /// The apparent position of the Sun.
+    ///
+    /// # Errors
+    /// Returns an error if the year is outside the range supported by
+    /// `CalculationMethod::TruncatedVsop87`.
+    ///
+    /// # Example
+    /// ```
+    /// use chrono::{DateTime, Utc};
+    /// use solar_calendar_events::SunPosition;
+    ///
+    /// let date_time: DateTime<Utc> = "2024-06-20T20:51:00Z".parse().unwrap();
+    /// let position = SunPosition::at(date_time).unwrap();
+    ///
+    /// assert!((position.longitude() - 90.0).abs() < 0.001);
+    /// assert!((position.declination() - 23.44).abs() < 0.01);
+    /// ```
+    pub fn at(date_time: DateTime<Utc>) -> Result<Self, AnnualSolarEventError> {
+        if !vsop87::YEAR_RANGE.contains(&date_time.year()) {
+            return Err(AnnualSolarEventError::YearOutOfRange(date_time.year()));
+        }
+        Ok(Self::from_julian_ephemeris_day(
+            delta_t::julian_ephemeris_day(date_time.to_julian_day()),
+        ))
+    }
+
+    /// Calculates the apparent position of the Sun at a Julian Ephemeris Day.
+    ///
+    /// # Arguments
+    /// * `julian_ephemeris_day` - The instant as a Julian Day in Terrestrial Time.
+    ///
+    /// # Returns
+    /// The apparent position of the Sun.
+    ///
+    /// # Errors
+    /// Returns an error if the year is outside the range supported by
+    /// `CalculationMethod::TruncatedVsop87`.
+    pub fn at_julian_ephemeris_day(
+        julian_ephemeris_day: f64,
+    ) -> Result<Self, AnnualSolarEventError> {
+        let year = DateTime::<Utc>::from_julian_day(julian_ephemeris_day)?.year();
+        if !vsop87::YEAR_RANGE.contains(&year) {
+            return Err(AnnualSolarEventError::YearOutOfRange(year));
+        }
+        Ok(Self::from_julian_ephemeris_day(julian_ephemeris_day))
+    }
+
+    /// Calculates the apparent position of the Sun at a Julian Ephemeris Day known to be within
+    /// the range of VSOP87.
+    pub(crate) fn from_julian_ephemeris_day(jde: f64) -> Self {
+        let t = (jde - J2000) / DAYS_PER_JULIAN_CENTURY;
+        let (longitude, latitude, distance) = vsop87::apparent_sun(jde);
+        let (delta_psi, delta_epsilon) = nutation::nutation(t);
+        let epsilon = (nutation::mean_obliquity(t) + delta_epsilon).to_radians();
+
+        // Meeus, equations 13.3 and 13.4.
+        let (lambda, beta) = (longitude.to_radians(), latitude.to_radians());
+        let right_ascension = (lambda.sin() * epsilon.cos() - beta.tan() * epsilon.sin())
+            .atan2(lambda.cos())
+            .to_degrees()
+            .rem_euclid(360.0);
+        let declination = (beta.sin() * epsilon.cos() + beta.cos() * epsilon.sin() * lambda.sin())
+            .asin()
+            .to_degrees();
+
+        // Meeus, equation 28.3, with the mean longitude of the Sun from equation 28.2.
+        let tau = t / 10.0;
+        let mean_longitude = 280.466_456_7
+            + tau
+                * (360_007.698_277_9
+                    + tau
+                        * (0.030_320_28
+                            + tau * (1.0 / 49_931.0 - tau * (1.0 / 15_300.0 + tau / 2_000_000.0))));
+        let equation_of_time =
+            (mean_longitude - 0.005_718_3 - right_ascension + delta_psi * epsilon.cos() + 180.0)
+                .rem_euclid(360.0)
+                - 180.0;
+
+        SunPosition {
+            longitude,
+            latitude,
+            distance,
+            right_ascension,
+            declination,
+            // Four minutes of time per degree.
+            equation_of_time: TimeDelta::nanoseconds(
+                (equation_of_time * 4.0 * NANOSECONDS_PER_MINUTE).round() as i64,
+            ),
+        }
+    }
+
+    /// Returns the apparent ecliptic longitude of the Sun, in degrees (0–360).
+    pub fn longitude(&self) -> f64 {
+        self.longitude
+    }
+
+    /// Returns the ecliptic latitude of the Sun, in degrees.
+    pub fn latitude(&self) -> f64 {
+        self.latitude
+    }
+
+    /// Returns the distance between the Earth and the Sun, in astronomical units.
+    pub fn distance(&self) -> f64 {
+        self.distance
+    }
+
+    /// Returns the apparent right ascension of the Sun, in degrees (0–360).
+    pub fn right_ascension(&self) -> f64 {
+        self.right_ascension
+    }
+
+    /// Returns the apparent declination of the Sun, in degrees.
+    pub fn declination(&self) -> f64 {
+        self.declination
+    }
+
+    /// Returns the equation of time, the apparent solar time minus the mean solar time.
+    pub fn equation_of_time(&self) -> TimeDelta {
+        self.equation_of_time
+    }
+}
@@ -0,0 +1,321 @@
+// Copyright (c) 2023-2024, Johan Thorén.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Conversions between the UTC, TAI, TT and GPS time scales for Julian Days.
+
+use std::path::Path;
+
+use chrono::{DateTime, NaiveDate};
+
+use crate::meeus::MJD_OFFSET;
+use crate::AnnualSolarEventError;
+
+/// Number of seconds in a day.
+const SECONDS_PER_DAY: f64 = 86_400.0;
+
+/// Julian Day of 1970-01-01T00:00:00.
+const UNIX_EPOCH_JULIAN_DAY: f64 = 2_440_587.5;
+
+/// Julian Day of 1900-01-01T00:00:00, the epoch of NTP timestamps.
+const NTP_EPOCH_JULIAN_DAY: f64 = 2_415_020.5;
+
+/// TT − TAI, in seconds.
+const TT_MINUS_TAI: f64 = 32.184;
+
+/// TAI − GPS, in seconds.
+const TAI_MINUS_GPS: f64 = 19.0;
+
+/// The offsets between TAI and UTC from 1961 until leap seconds were introduced in 1972, when UTC
+/// was steered with fractional steps and frequency offsets: the year and month from which each
+/// entry applies, the offset in seconds, the reference MJD and the rate in seconds per day.
+#[rustfmt::skip]
+const DRIFTING_OFFSETS: [(i32, u32, f64, f64, f64); 13] = [
+    (1961, 1, 1.422_818_0, 37_300.0, 0.001_296),
+    (1961, 8, 1.372_818_0, 37_300.0, 0.001_296),
+    (1962, 1, 1.845_858_0, 37_665.0, 0.001_123_2),
+    (1963, 11, 1.945_858_0, 37_665.0, 0.001_123_2),
+    (1964, 1, 3.240_130_0, 38_761.0, 0.001_296),
+    (1964, 4, 3.340_130_0, 38_761.0, 0.001_296),
+    (1964, 9, 3.440_130_0, 38_761.0, 0.001_296),
+    (1965, 1, 3.540_130_0, 38_761.0, 0.001_296),
+    (1965, 3, 3.640_130_0, 38_761.0, 0.001_296),
+    (1965, 7, 3.740_130_0, 38_761.0, 0.001_296),
+    (1965, 9, 3.840_130_0, 38_761.0, 0.001_296),
+    (1966, 1, 4.313_170_0, 39_126.0, 0.002_592),
+    (1968, 2, 4.213_170_0, 39_126.0, 0.002_592),
+];
+
+/// The leap seconds: the year and month from which each value of TAI − UTC applies.
+#[rustfmt::skip]
+const LEAP_SECONDS: [(i32, u32, f64); 28] = [
+    (1972, 1, 10.0), (1972, 7, 11.0), (1973, 1, 12.0), (1974, 1, 13.0),
+    (1975, 1, 14.0), (1976, 1, 15.0), (1977, 1, 16.0), (1978, 1, 17.0),
+    (1979, 1, 18.0), (1980, 1, 19.0), (1981, 7, 20.0), (1982, 7, 21.0),
+    (1983, 7, 22.0), (1985, 7, 23.0), (1988, 1, 24.0), (1990, 1, 25.0),
+    (1991, 1, 26.0), (1992, 7, 27.0), (1993, 7, 28.0), (1994, 7, 29.0),
+    (1996, 1, 30.0), (1997, 7, 31.0), (1999, 1, 32.0), (2006, 1, 33.0),
+    (2009, 1, 34.0), (2012, 7, 35.0), (2015, 7, 36.0), (2017, 1, 37.0),
+];
+
+/// A time scale that a Julian Day can be expressed in.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum TimeScale {
+    /// Coordinated Universal Time.
+    Utc,
+
+    /// International Atomic Time.
+    Tai,
+
+    /// Terrestrial Time, the time scale of the Julian Ephemeris Day.
+    Tt,
+
+    /// GPS Time.
+    Gps,
+}
+
+impl TimeScale {
+    /// Returns the constant offset from TAI to the time scale, in seconds, or `None` for UTC.
+    fn offset_from_tai(&self) -> Option<f64> {
+        match self {
+            TimeScale::Utc => None,
+            TimeScale::Tai => Some(0.0),
+            TimeScale::Tt => Some(TT_MINUS_TAI),
+            TimeScale::Gps => Some(-TAI_MINUS_GPS),
+        }
+    }
+}
+
+/// A single entry of a `LeapSecondTable`.
+#[derive(Clone, Debug, PartialEq)]
+struct LeapSecondEntry {
+    /// The Julian Day (UTC) from which the entry applies.
+    julian_day: f64,
+
+    /// TAI − UTC at the reference MJD, in seconds.
+    offset: f64,
+
+    /// The reference MJD for the drifting offsets before 1972.
+    reference_mjd: f64,
+
+    /// The rate of change of TAI − UTC, in seconds per day.
+    rate: f64,
+}
+
+impl LeapSecondEntry {
+    /// Returns TAI − UTC at the given Julian Day (UTC).
+    fn tai_minus_utc(&self, julian_day: f64) -> f64 {
+        self.offset + (julian_day - MJD_OFFSET - self.reference_mjd) * self.rate
+    }
+}
+
+/// A table of the offsets between TAI and UTC, used to convert Julian Days between time scales.
+///
+/// The built-in table, returned by `LeapSecondTable::default()`, covers the drifting offsets from
+/// 1961 and all leap seconds up to and including the one at the end of 2016 (TAI − UTC = 37 s).
+/// A newer table can be loaded from an IETF `leap-seconds.list` file.
+///
+/// Before the first entry of a table, the offset of the first entry is used.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LeapSecondTable {
+    entries: Vec<LeapSecondEntry>,
+    expires: Option<f64>,
+}
+
+impl Default for LeapSecondTable {
+    fn default() -> Self {
+        let drifting =
+            DRIFTING_OFFSETS
+                .iter()
+                .map(
+                    |&(year, month, offset, reference_mjd, rate)| LeapSecondEntry {
+                        julian_day: first_of_month_julian_day(year, month),
+                        offset,
+                        reference_mjd,
+                        rate,
+                    },
+                );
+        let leap_seconds = LEAP_SECONDS
+            .iter()
+            .map(|&(year, month, offset)| LeapSecondEntry {
+                julian_day: first_of_month_julian_day(year, month),
+                offset,
+                reference_mjd: 0.0,
+                rate: 0.0,
+            });
+
+        Self {
+            entries: drifting.chain(leap_seconds).collect(),
+            expires: None,
+        }
+    }
+}
+
+impl LeapSecondTable {
+    /// Parses the contents of an IETF `leap-seconds.list` file.
+    ///
+    /// Each data line holds an NTP timestamp (seconds since 1900-01-01) and the value of
+    /// TAI − UTC from that instant. The expiration date is read from the `#@` line, if present.
+    /// Other lines starting with `#` are ignored.
+    ///
+    /// # Arguments
+    /// * `contents` - The contents of the file.
+    ///
+    /// # Returns
+    /// The parsed leap second table.
+    ///
+    /// # Errors
+    /// Returns an error if a line cannot be parsed or if the file holds no entries.
+    ///
+    /// # Example
+    /// ```
+    /// use solar_calendar_events::LeapSecondTable;
+    ///
+    /// let table = LeapSecondTable::parse_leap_seconds_list(
+    ///     "#@\t3960057600\n2272060800\t10\t# 1 Jan 1972\n2287785600\t11\t# 1 Jul 1972\n",
+    /// )
+    /// .unwrap();
+    ///
+    /// assert_eq!(table.tai_minus_utc(2_441_500.0), 11.0);
+    /// ```
+    pub fn parse_leap_seconds_list(contents: &str) -> Result<Self, AnnualSolarEventError> {
+        let mut entries = Vec::new();
+        let mut expires = None;
+
+        for (index, line) in contents.lines().enumerate() {
+            let line_number = index + 1;
+            let line = line.trim();
+            if let Some(expiration) = line.strip_prefix("#@") {
+                let ntp = parse_field(expiration.trim(), line_number)?;
+                expires = Some(NTP_EPOCH_JULIAN_DAY + ntp / SECONDS_PER_DAY);
+                continue;
+            }
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let data = line.split('#').next().unwrap_or_default();
+            let mut fields = data.split_whitespace();
+            let (Some(ntp), Some(offset)) = (fields.next(), fields.next()) else {
+                return Err(AnnualSolarEventError::LeapSecondParseError(line_number));
+            };
+            entries.push(LeapSecondEntry {
+                julian_day: NTP_EPOCH_JULIAN_DAY + parse_field(ntp, line_number)? / SECONDS_PER_DAY,
+                offset: parse_field(offset, line_number)?,
+                reference_mjd: 0.0,
+                rate: 0.0,
+            });
+        }
+
+        if entries.is_empty() {
+            return Err(AnnualSolarEventError::LeapSecondParseError(0));
+        }
+        entries.sort_by(|a, b| a.julian_day.total_cmp(&b.julian_day));
+
+        Ok(Self { entries, expires })
+    }
+
+    /// Loads an IETF `leap-seconds.list` file from disk.
+    ///
+    /// # Arguments
+    /// * `path` - The path to the file.
+    ///
+    /// # Returns
+    /// The parsed leap second table.
+    ///
+    /// # Errors
+    /// Returns an error if the file cannot be read or parsed.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, AnnualSolarEventError> {
+        let contents = std::fs::read_to_string(path)
+            .map_err(|e| AnnualSolarEventError::LeapSecondFileError(e.to_string()))?;
+        Self::parse_leap_seconds_list(&contents)
+    }
+
+    /// Returns the Julian Day (UTC) after which the table should no longer be trusted, if known.
+    pub fn expires(&self) -> Option<f64> {
+        self.expires
+    }
+
+    /// Returns TAI − UTC at the given instant.
+    ///
+    /// # Arguments
+    /// * `utc_julian_day` - The Julian Day in UTC.
+    ///
+    /// # Returns
+    /// TAI − UTC, in seconds.
+    pub fn tai_minus_utc(&self, utc_julian_day: f64) -> f64 {
+        let index = self
+            .entries
+            .partition_point(|entry| entry.julian_day <= utc_julian_day);
+        let entry = &self.entries[index.saturating_sub(1)];
+        entry.tai_minus_utc(utc_julian_day.max(entry.julian_day))
+    }
+
+    /// Converts a Julian Day from one time scale to another.
+    ///
+    /// During a leap second, UTC repeats the Julian Day of the following second, so converting
+    /// such an instant to UTC and back is ambiguous.
+    ///
+    /// # Arguments
+    /// * `julian_day` - The Julian Day to convert.
+    /// * `from` - The time scale of `julian_day`.
+    /// * `to` - The time scale to convert to.
+    ///
+    /// # Returns
+    /// The Julian Day in the `to` time scale.
+    ///
+    /// # Example
+    /// ```
+    /// use solar_calendar_events::{LeapSecondTable, TimeScale};
+    ///
+    /// let table = LeapSecondTable::default();
+    /// let utc = 2_459_000.5;
+    /// let tt = table.convert(utc, TimeScale::Utc, TimeScale::Tt);
+    ///
+    /// assert!(((tt - utc) * 86_400.0 - 69.184).abs() < 1e-4);
+    /// ```
+    pub fn convert(&self, julian_day: f64, from: TimeScale, to: TimeScale) -> f64 {
+        if from == to {
+            return julian_day;
+        }
+        let tai = match from.offset_from_tai() {
+            Some(offset) => julian_day - offset / SECONDS_PER_DAY,
+            None => julian_day + self.tai_minus_utc(julian_day) / SECONDS_PER_DAY,
+        };
+        match to.offset_from_tai() {
+            Some(offset) => tai + offset / SECONDS_PER_DAY,
+            None => self.utc_from_tai(tai),
+        }
+    }
+
+    /// Converts a Julian Day in TAI to UTC.
+    fn utc_from_tai(&self, tai: f64) -> f64 {
+        let mut utc = tai - self.tai_minus_utc(tai) / SECONDS_PER_DAY;
+        for _ in 0..3 {
+            utc = tai - self.tai_minus_utc(utc) / SECONDS_PER_DAY;
+        }
+        utc
+    }
+}
+
+/// Returns the Julian Day at midnight on the first day of the given month.
+fn first_of_month_julian_day(year: i32, month: u32) -> f64 {
+    let days = NaiveDate::from_ymd_opt(year, month, 1)
+        .map(|date| {
+            date.signed_duration_since(DateTime::UNIX_EPOCH.date_naive())
+                .num_days()
+        })
+        .unwrap_or_default();
+    UNIX_EPOCH_JULIAN_DAY + days as f64
+}
+
+/// Parses a numeric field of a `leap-seconds.list` file.
+fn parse_field(field: &str, line_number: usize) -> Result<f64, AnnualSolarEventError> {
+    field
+        .parse()
+        .map_err(|_| AnnualSolarEventError::LeapSecondParseError(line_number))
+}
@@ -0,0 +1,356 @@
+// Copyright (c) 2023-2024, Johan Thorén.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The ingresses of the Sun into the signs of the tropical and sidereal zodiacs.
+
+use std::slice;
+
+use chrono::{DateTime, Utc};
+
+use crate::meeus::{DAYS_PER_JULIAN_CENTURY, J2000};
+use crate::{
+    delta_t, nutation, vsop87, AnnualSolarEvent, AnnualSolarEventError, CalculationMethod,
+    DecemberSolstice, JulianDateTimeUtc, JuneSolstice, MarchEquinox, SeptemberEquinox,
+};
+
+/// The mean length of a sign, in days.
+const DAYS_PER_SIGN: f64 = 365.242_189_6 / 12.0;
+
+/// One of the twelve signs of the zodiac, each spanning 30° of longitude.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum ZodiacSign {
+    /// Aries, from 0°.
+    Aries,
+    /// Taurus, from 30°.
+    Taurus,
+    /// Gemini, from 60°.
+    Gemini,
+    /// Cancer, from 90°.
+    Cancer,
+    /// Leo, from 120°.
+    Leo,
+    /// Virgo, from 150°.
+    Virgo,
+    /// Libra, from 180°.
+    Libra,
+    /// Scorpio, from 210°.
+    Scorpio,
+    /// Sagittarius, from 240°.
+    Sagittarius,
+    /// Capricorn, from 270°.
+    Capricorn,
+    /// Aquarius, from 300°.
+    Aquarius,
+    /// Pisces, from 330°.
+    Pisces,
+}
+
+impl ZodiacSign {
+    /// All twelve signs, starting with Aries.
+    pub const ALL: [ZodiacSign; 12] = [
+        ZodiacSign::Aries,
+        ZodiacSign::Taurus,
+        ZodiacSign::Gemini,
+        ZodiacSign::Cancer,
+        ZodiacSign::Leo,
+        ZodiacSign::Virgo,
+        ZodiacSign::Libra,
+        ZodiacSign::Scorpio,
+        ZodiacSign::Sagittarius,
+        ZodiacSign::Capricorn,
+        ZodiacSign::Aquarius,
+        ZodiacSign::Pisces,
+    ];
+
+    /// Returns the longitude at which the sign starts, in degrees, measured in the zodiac in use.
+    pub fn longitude(&self) -> f64 {
+        30.0 * *self as u8 as f64
+    }
+
+    /// Returns the English name of the sign.
+    pub fn name(&self) -> &'static str {
+        match self {
+            ZodiacSign::Aries => "Aries",
+            ZodiacSign::Taurus => "Taurus",
+            ZodiacSign::Gemini => "Gemini",
+            ZodiacSign::Cancer => "Cancer",
+            ZodiacSign::Leo => "Leo",
+            ZodiacSign::Virgo => "Virgo",
+            ZodiacSign::Libra => "Libra",
+            ZodiacSign::Scorpio => "Scorpio",
+            ZodiacSign::Sagittarius => "Sagittarius",
+            ZodiacSign::Capricorn => "Capricorn",
+            ZodiacSign::Aquarius => "Aquarius",
+            ZodiacSign::Pisces => "Pisces",
+        }
+    }
+}
+
+/// An ayanamsa, the difference between the tropical and the sidereal longitude.
+///
+/// Each ayanamsa is defined by its mean value at a reference epoch, from which it grows with the
+/// general precession in longitude (IAU 1976). The nutation in longitude is added to give the
+/// true ayanamsa, so that sidereal longitudes are measured from a point fixed among the stars.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Ayanamsa {
+    /// The Lahiri (Chitrapaksha) ayanamsa of the Indian Astronomical Ephemeris, whose true value,
+    /// including nutation, is 23°15′00.658″ at 1956 March 21, 0h ET.
+    Lahiri,
+
+    /// The Fagan–Bradley ayanamsa of western sidereal astrology, 24°02′31.36″ at 1950.0.
+    FaganBradley,
+
+    /// The ayanamsa of B. V. Raman, 21°00′52″ at 1900.0.
+    Raman,
+
+    /// An ayanamsa with the given mean value at a reference epoch.
+    Custom {
+        /// The reference epoch, as a Julian Ephemeris Day.
+        julian_ephemeris_day: f64,
+        /// The mean ayanamsa at the reference epoch, in degrees.
+        degrees: f64,
+    },
+}
+
+impl Ayanamsa {
+    /// Returns the reference epoch, as a Julian Ephemeris Day, and the mean value at the epoch.
+    fn reference(&self) -> (f64, f64) {
+        match self {
+            // The published value is the true ayanamsa, so the nutation in longitude at the
+            // epoch, +16.78″, is subtracted to give the mean value.
+            Ayanamsa::Lahiri => (2_435_553.5, 23.250_182_778 - 0.004_660_222),
+            Ayanamsa::FaganBradley => (2_433_282.423_46, 24.042_044_444),
+            Ayanamsa::Raman => (2_415_020.0, 21.014_444),
+            Ayanamsa::Custom {
+                julian_ephemeris_day,
+                degrees,
+            } => (*julian_ephemeris_day, *degrees),
+        }
+    }
+
+    /// Calculates the true ayanamsa at an instant.
+    ///
+    /// # Arguments
+    /// * `julian_ephemeris_day` - The instant as a Julian Day in Terrestrial Time.
+    ///
+    /// # Returns
+    /// The ayanamsa, including the nutation in longitude, in degrees.
+    ///
+    /// # Example
+    /// ```
+    /// use solar_calendar_events::Ayanamsa;
+    ///
+    /// // About 23°51′ at J2000.0.
+    /// assert!((Ayanamsa::Lahiri.degrees(2_451_545.0) - 23.85).abs() < 0.01);
+    /// ```
+    pub fn degrees(&self, julian_ephemeris_day: f64) -> f64 {
+        let (epoch, degrees) = self.reference();
+        let t = (julian_ephemeris_day - J2000) / DAYS_PER_JULIAN_CENTURY;
+        let (delta_psi, _) = nutation::nutation(t);
+        degrees + general_precession(julian_ephemeris_day) - general_precession(epoch) + delta_psi
+    }
+}
+
+/// Calculates the general precession in longitude since J2000.0, in degrees (Lieske et al.,
+/// 1977).
+fn general_precession(julian_ephemeris_day: f64) -> f64 {
+    let t = (julian_ephemeris_day - J2000) / DAYS_PER_JULIAN_CENTURY;
+    (5_029.096_6 * t + 1.111_13 * t * t - 0.000_006 * t * t * t) / 3_600.0
+}
+
+/// The zodiac in which the longitude of the Sun is measured.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Zodiac {
+    /// The tropical zodiac, measured from the March equinox.
+    #[default]
+    Tropical,
+
+    /// A sidereal zodiac, measured from a fixed point among the stars given by an ayanamsa.
+    Sidereal(Ayanamsa),
+}
+
+impl Zodiac {
+    /// Converts a longitude in this zodiac to the tropical (apparent) longitude at an instant.
+    fn tropical_longitude(&self, longitude: f64, julian_ephemeris_day: f64) -> f64 {
+        match self {
+            Zodiac::Tropical => longitude,
+            Zodiac::Sidereal(ayanamsa) => {
+                (longitude + ayanamsa.degrees(julian_ephemeris_day)).rem_euclid(360.0)
+            }
+        }
+    }
+
+    /// Solves for the instant when the Sun enters a sign, starting from an estimate.
+    fn solve_ingress(&self, sign: ZodiacSign, estimate: f64) -> f64 {
+        // The ayanamsa changes by less than 1.5″ a day, so two passes are enough.
+        let mut jde = estimate;
+        for _ in 0..2 {
+            jde =
+                vsop87::solve_solar_longitude(self.tropical_longitude(sign.longitude(), jde), jde);
+        }
+        jde
+    }
+}
+
+/// The instant the Sun enters a sign of the zodiac.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ZodiacIngress {
+    sign: ZodiacSign,
+    julian_day: f64,
+    julian_ephemeris_day: f64,
+    date_time: DateTime<Utc>,
+}
+
+impl ZodiacIngress {
+    /// Returns the sign the Sun enters.
+    pub fn sign(&self) -> ZodiacSign {
+        self.sign
+    }
+
+    /// Returns the date and time of the ingress as a `DateTime<Utc>`.
+    pub fn date_time(&self) -> DateTime<Utc> {
+        self.date_time
+    }
+
+    /// Returns the Julian Day Number of the ingress in Universal Time.
+    pub fn julian_day(&self) -> f64 {
+        self.julian_day
+    }
+
+    /// Returns the Julian Ephemeris Day of the ingress, i.e. the Julian Day in Terrestrial
+    /// (Dynamical) Time.
+    pub fn julian_ephemeris_day(&self) -> f64 {
+        self.julian_ephemeris_day
+    }
+}
+
+/// Contains the ingresses of the Sun into all twelve signs of a zodiac, starting with the
+/// ingress into Aries in a given year.
+///
+/// The ingresses follow the order of the signs, so the last ones (into Aquarius and Pisces in the
+/// tropical zodiac) fall early in the next calendar year.
+#[derive(Debug)]
+pub struct ZodiacIngresses {
+    year: i32,
+    zodiac: Zodiac,
+    ingresses: Vec<ZodiacIngress>,
+}
+
+impl ZodiacIngresses {
+    /// Creates a new `ZodiacIngresses` instance for the specified year and zodiac.
+    ///
+    /// Returns an error if the year is outside the valid range.
+    ///
+    /// # Example
+    /// ```
+    /// use solar_calendar_events::{Ayanamsa, Zodiac, ZodiacIngresses, ZodiacSign};
+    ///
+    /// let tropical = ZodiacIngresses::for_year(2024, Zodiac::Tropical).unwrap();
+    /// let sidereal = ZodiacIngresses::for_year(2024, Zodiac::Sidereal(Ayanamsa::Lahiri)).unwrap();
+    ///
+    /// let date = |ingresses: &ZodiacIngresses| {
+    ///     ingresses.get(ZodiacSign::Aries).date_time().format("%F").to_string()
+    /// };
+    ///
+    /// assert_eq!(date(&tropical), "2024-03-20");
+    /// assert_eq!(date(&sidereal), "2024-04-13");
+    /// ```
+    pub fn for_year(year: i32, zodiac: Zodiac) -> Result<Self, AnnualSolarEventError> {
+        Self::for_year_with_method(year, zodiac, CalculationMethod::default())
+    }
+
+    /// Creates a new `ZodiacIngresses` instance for the specified year and zodiac, using the
+    /// given calculation method.
+    ///
+    /// In the tropical zodiac, the ingresses into Aries, Cancer, Libra and Capricorn are the
+    /// equinoxes and solstices, and match `AnnualSolarEvents`. Meeus gives no tables for the
+    /// other ingresses, or for any ingress in a sidereal zodiac, so they are always solved with
+    /// VSOP87.
+    ///
+    /// Returns an error if the year is outside the range supported by `method`.
+    pub fn for_year_with_method(
+        year: i32,
+        zodiac: Zodiac,
+        method: CalculationMethod,
+    ) -> Result<Self, AnnualSolarEventError> {
+        if !method.year_range().contains(&year) {
+            return Err(AnnualSolarEventError::YearOutOfRange(year));
+        }
+
+        let mut ingresses: Vec<ZodiacIngress> = Vec::with_capacity(ZodiacSign::ALL.len());
+        for sign in ZodiacSign::ALL {
+            let julian_ephemeris_day = match (zodiac, sign) {
+                (Zodiac::Tropical, ZodiacSign::Aries) => {
+                    MarchEquinox::for_year_with_method(year, method)?.julian_ephemeris_day()
+                }
+                (Zodiac::Tropical, ZodiacSign::Cancer) => {
+                    JuneSolstice::for_year_with_method(year, method)?.julian_ephemeris_day()
+                }
+                (Zodiac::Tropical, ZodiacSign::Libra) => {
+                    SeptemberEquinox::for_year_with_method(year, method)?.julian_ephemeris_day()
+                }
+                (Zodiac::Tropical, ZodiacSign::Capricorn) => {
+                    DecemberSolstice::for_year_with_method(year, method)?.julian_ephemeris_day()
+                }
+                _ => {
+                    let estimate = match ingresses.last() {
+                        Some(previous) => previous.julian_ephemeris_day + DAYS_PER_SIGN,
+                        None => {
+                            let march_equinox = vsop87::estimate_solar_longitude(0.0, year);
+                            let longitude = zodiac.tropical_longitude(0.0, march_equinox);
+                            vsop87::estimate_solar_longitude(longitude, year)
+                        }
+                    };
+                    zodiac.solve_ingress(sign, estimate)
+                }
+            };
+            let julian_day = delta_t::universal_julian_day(julian_ephemeris_day);
+            ingresses.push(ZodiacIngress {
+                sign,
+                julian_day,
+                julian_ephemeris_day,
+                date_time: DateTime::<Utc>::from_julian_day(julian_day)?,
+            });
+        }
+
+        Ok(Self {
+            year,
+            zodiac,
+            ingresses,
+        })
+    }
+
+    /// Returns the ingress into the given sign.
+    pub fn get(&self, sign: ZodiacSign) -> &ZodiacIngress {
+        &self.ingresses[sign as usize]
+    }
+
+    /// Returns an iterator over the ingresses in chronological order, starting with Aries.
+    pub fn iter(&self) -> slice::Iter<'_, ZodiacIngress> {
+        self.ingresses.iter()
+    }
+
+    /// Returns the year of the ingress into Aries.
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    /// Returns the zodiac of these ingresses.
+    pub fn zodiac(&self) -> Zodiac {
+        self.zodiac
+    }
+}
+
+impl<'a> IntoIterator for &'a ZodiacIngresses {
+    type Item = &'a ZodiacIngress;
+    type IntoIter = slice::Iter<'a, ZodiacIngress>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.ingresses.iter()
+    }
+}
@@ -0,0 +1,116 @@
+#[cfg(test)]
+mod tests {
+    use std::error::Error;
+
+    use chrono::{DateTime, Datelike, TimeDelta, Utc};
+    use solar_calendar_events::*;
+
+    // Published instants of the perihelion and aphelion, from the U.S. Naval Observatory.
+    const PUBLISHED: [(i32, &str, &str); 3] = [
+        (2023, "2023-01-04T16:17:00Z", "2023-07-06T20:06:00Z"),
+        (2024, "2024-01-03T00:39:00Z", "2024-07-05T05:06:00Z"),
+        (2025, "2025-01-04T13:28:00Z", "2025-07-03T19:55:00Z"),
+    ];
+
+    fn assert_near(actual: DateTime<Utc>, expected: &str, max_diff: TimeDelta) {
+        let expected: DateTime<Utc> = expected.parse().unwrap();
+        let diff = (actual - expected).abs();
+
+        println!("{} {}: {}", actual, expected, diff);
+        assert!(diff < max_diff);
+    }
+
+    #[test]
+    fn apsides_match_published_instants() -> Result<(), Box<dyn Error>> {
+        for (year, perihelion, aphelion) in PUBLISHED {
+            for (method, max_diff) in [
+                (CalculationMethod::Meeus, TimeDelta::hours(12)),
+                (CalculationMethod::TruncatedVsop87, TimeDelta::hours(1)),
+            ] {
+                assert_near(
+                    Perihelion::for_year_with_method(year, method)?.date_time(),
+                    perihelion,
+                    max_diff,
+                );
+                assert_near(
+                    Aphelion::for_year_with_method(year, method)?.date_time(),
+                    aphelion,
+                    max_diff,
+                );
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn apsides_are_the_extremes_of_the_distance() -> Result<(), Box<dyn Error>> {
+        for year in [-1999, 0, 2024, 5999] {
+            let method = CalculationMethod::TruncatedVsop87;
+            let perihelion = Perihelion::for_year_with_method(year, method)?;
+            let aphelion = Aphelion::for_year_with_method(year, method)?;
+
+            for days in [-2.0, 2.0] {
+                let before_or_after =
+                    SunPosition::at_julian_ephemeris_day(perihelion.julian_ephemeris_day() + days)?;
+                assert!(before_or_after.distance() > perihelion.distance());
+
+                let before_or_after =
+                    SunPosition::at_julian_ephemeris_day(aphelion.julian_ephemeris_day() + days)?;
+                assert!(before_or_after.distance() < aphelion.distance());
+            }
+            // Before about 1770 the perihelion of a year falls after its aphelion.
+            let half_year = aphelion.julian_ephemeris_day() - perihelion.julian_ephemeris_day();
+            assert!((170.0..195.0).contains(&half_year.abs()));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn apsides_fall_in_their_year() -> Result<(), Box<dyn Error>> {
+        for method in [CalculationMethod::Meeus, CalculationMethod::TruncatedVsop87] {
+            let mut missing = Vec::new();
+            for year in method.year_range() {
+                match Perihelion::for_year_with_method(year, method) {
+                    Ok(perihelion) => {
+                        assert_eq!(perihelion.year(), year);
+                        assert_eq!(perihelion.date_time().year(), year);
+                    }
+                    Err(AnnualSolarEventError::NoEventInYear(_)) => missing.push(year),
+                    Err(error) => return Err(error.into()),
+                }
+                let aphelion = Aphelion::for_year_with_method(year, method)?;
+                assert_eq!(aphelion.year(), year);
+                assert_eq!(aphelion.date_time().year(), year);
+            }
+
+            // Only years around 1800, when the perihelion passes New Year's Day, lack one.
+            println!("{:?}: {:?}", method, missing);
+            assert!(missing.iter().all(|year| (1750..1900).contains(year)));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn annual_solar_events_include_the_apsides() -> Result<(), Box<dyn Error>> {
+        let events = AnnualSolarEvents::for_year(2024)?;
+
+        let perihelion = events.perihelion().unwrap();
+        assert!(perihelion.date_time() < events.march_equinox().date_time());
+        assert!(events.aphelion().date_time() > events.june_solstice().date_time());
+        assert!((perihelion.distance() - 0.983_31).abs() < 0.000_01);
+        assert!((events.aphelion().distance() - 1.016_73).abs() < 0.000_01);
+        assert_eq!(perihelion.year(), 2024);
+
+        // The perihelion of 1802 falls on 1801-12-31 and the next one on 1803-01-01.
+        assert!(AnnualSolarEvents::for_year(1802)?.perihelion().is_none());
+        assert_eq!(
+            Perihelion::for_year(1802).err(),
+            Some(AnnualSolarEventError::NoEventInYear(1802))
+        );
+        assert_eq!(
+            Aphelion::for_year(3001).err(),
+            Some(AnnualSolarEventError::YearOutOfRange(3001))
+        );
+        Ok(())
+    }
+}
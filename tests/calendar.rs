@@ -0,0 +1,163 @@
+#[cfg(test)]
+mod tests {
+    use std::error::Error;
+
+    use chrono::{DateTime, Datelike, NaiveDate, TimeDelta, Utc};
+    use solar_calendar_events::*;
+
+    fn julian(year: i32, month: u32, day: u32) -> JulianCalendarDate {
+        JulianCalendarDate::from_ymd(year, month, day).unwrap()
+    }
+
+    fn gregorian(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn julian_calendar_date_matches_meeus_examples() -> Result<(), Box<dyn Error>> {
+        // Example 7.b: 333 January 27.5.
+        assert_eq!(julian(333, 1, 27).to_julian_day() + 0.5, 1842713.0);
+        assert_eq!(
+            JulianCalendarDate::from_julian_day(1842713.0)?,
+            julian(333, 1, 27)
+        );
+
+        // Example 7.c: JD 1507900.13 is -584 May 28.63.
+        assert_eq!(
+            JulianCalendarDate::from_julian_day(1507900.13)?,
+            julian(-584, 5, 28)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn julian_calendar_date_validates_leap_years() {
+        assert!(JulianCalendarDate::from_ymd(1500, 2, 29).is_ok());
+        assert!(JulianCalendarDate::from_ymd(-1, 2, 29).is_err());
+        assert!(JulianCalendarDate::from_ymd(0, 2, 29).is_ok());
+        assert_eq!(
+            JulianCalendarDate::from_ymd(1000, 4, 31),
+            Err(AnnualSolarEventError::InvalidDateError(1000, 4, 31))
+        );
+        assert_eq!(
+            JulianCalendarDate::from_ymd(1000, 13, 1),
+            Err(AnnualSolarEventError::MonthOutOfRange(13))
+        );
+    }
+
+    #[test]
+    fn julian_calendar_date_converts_to_and_from_gregorian() -> Result<(), Box<dyn Error>> {
+        let pairs = [
+            (julian(1582, 10, 4), gregorian(1582, 10, 14)),
+            (julian(1582, 10, 5), gregorian(1582, 10, 15)),
+            (julian(1500, 2, 29), gregorian(1500, 3, 10)),
+            (julian(200, 3, 1), gregorian(200, 3, 1)),
+            (julian(1, 1, 3), gregorian(1, 1, 1)),
+            (julian(-1000, 3, 1), gregorian(-1000, 2, 20)),
+            (julian(1900, 2, 29), gregorian(1900, 3, 13)),
+        ];
+
+        for (julian_date, gregorian_date) in pairs {
+            assert_eq!(julian_date.to_gregorian()?, gregorian_date);
+            assert_eq!(
+                JulianCalendarDate::from_gregorian(gregorian_date)?,
+                julian_date
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn julian_calendar_date_round_trips_through_julian_day() -> Result<(), Box<dyn Error>> {
+        let mut date = gregorian(-2000, 1, 1);
+
+        while date.year() < 6000 {
+            let julian_date = JulianCalendarDate::from_gregorian(date)?;
+            let julian_day = julian_date.to_julian_day();
+
+            assert_eq!(
+                JulianCalendarDate::from_julian_day(julian_day)?,
+                julian_date
+            );
+            assert_eq!(julian_date.to_gregorian()?, date);
+            date += TimeDelta::days(997);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn julian_calendar_date_is_formatted_like_chrono() {
+        assert_eq!(julian(333, 1, 27).to_string(), "0333-01-27");
+        assert_eq!(julian(-584, 5, 28).to_string(), "-0584-05-28");
+        assert_eq!(julian(12345, 5, 28).to_string(), "+12345-05-28");
+    }
+
+    #[test]
+    fn historical_date_switches_calendar_at_the_gregorian_reform() -> Result<(), Box<dyn Error>> {
+        let last_julian_day = HistoricalDate::from_julian_day(2299160.0)?;
+        let first_gregorian_day = HistoricalDate::from_julian_day(2299160.5)?;
+
+        assert_eq!(last_julian_day, HistoricalDate::Julian(julian(1582, 10, 4)));
+        assert_eq!(
+            first_gregorian_day,
+            HistoricalDate::Gregorian(gregorian(1582, 10, 15))
+        );
+        assert_eq!(last_julian_day.to_gregorian()?, gregorian(1582, 10, 14));
+        assert_eq!(
+            (last_julian_day.year(), last_julian_day.month()),
+            (1582, 10)
+        );
+        assert_eq!(first_gregorian_day.day(), 15);
+        Ok(())
+    }
+
+    #[test]
+    fn date_time_from_julian_day_uses_the_proleptic_gregorian_calendar() {
+        let date_time = DateTime::<Utc>::from_julian_day(1842713.0).unwrap();
+
+        assert_eq!(date_time.date_naive(), gregorian(333, 1, 28));
+        assert_eq!(date_time.to_julian_day(), 1842713.0);
+    }
+
+    #[test]
+    fn julian_leap_day_converts_to_a_proleptic_gregorian_date_time() -> Result<(), Box<dyn Error>> {
+        // 1500 is a leap year in the Julian calendar only.
+        let julian_day = julian(1500, 2, 29).to_julian_day() + 0.5;
+        let date_time = DateTime::<Utc>::from_julian_day(julian_day)?;
+
+        assert_eq!(date_time.date_naive(), gregorian(1500, 3, 10));
+        assert_eq!(date_time.to_julian_day(), julian_day);
+        assert_eq!(
+            HistoricalDate::from_julian_day(julian_day)?,
+            HistoricalDate::Julian(julian(1500, 2, 29))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn historical_date_of_an_event_matches_its_date_time() -> Result<(), Box<dyn Error>> {
+        for year in [-1000, 325, 1500, 1582, 1583, 2024] {
+            let event = DecemberSolstice::for_year(year)?;
+            let historical = HistoricalDate::from_gregorian(event.date_time().date_naive())?;
+
+            assert_eq!(historical, event.historical_date()?);
+            assert_eq!(historical.to_gregorian()?, event.date_time().date_naive());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn historical_date_of_events_before_the_reform() -> Result<(), Box<dyn Error>> {
+        // Before the reform the Julian calendar drifted, so the March equinox moved from around
+        // March 21 at the Council of Nicaea in 325 to March 11 by 1582.
+        let nicaea = MarchEquinox::for_year(325)?.historical_date()?;
+        let reform = MarchEquinox::for_year(1582)?.historical_date()?;
+        let after = MarchEquinox::for_year(1583)?.historical_date()?;
+
+        assert!(matches!(nicaea, HistoricalDate::Julian(_)));
+        assert_eq!((nicaea.month(), nicaea.day()), (3, 20));
+        assert_eq!(reform, HistoricalDate::Julian(julian(1582, 3, 10)));
+        assert_eq!(after, HistoricalDate::Gregorian(gregorian(1583, 3, 21)));
+        Ok(())
+    }
+}
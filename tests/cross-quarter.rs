@@ -0,0 +1,89 @@
+#[cfg(test)]
+mod tests {
+    use std::error::Error;
+
+    use chrono::Datelike;
+    use solar_calendar_events::*;
+
+    fn assert_at_solar_longitude<T: AnnualSolarEvent>(event: &T) {
+        let diff = (event.sun_position().longitude() - T::solar_longitude() + 180.0)
+            .rem_euclid(360.0)
+            - 180.0;
+
+        println!("{} {}: {}", event.date_time(), T::solar_longitude(), diff);
+        assert!(diff.abs() < 1e-6);
+    }
+
+    #[test]
+    fn cross_quarter_days_are_at_their_solar_longitude() -> Result<(), Box<dyn Error>> {
+        for year in [-2000, -1000, 0, 1000, 2024, 3000, 6000] {
+            let method = CalculationMethod::TruncatedVsop87;
+
+            assert_at_solar_longitude(&Imbolc::for_year_with_method(year, method)?);
+            assert_at_solar_longitude(&Beltane::for_year_with_method(year, method)?);
+            assert_at_solar_longitude(&Lughnasadh::for_year_with_method(year, method)?);
+            assert_at_solar_longitude(&Samhain::for_year_with_method(year, method)?);
+        }
+        Ok(())
+    }
+
+    fn polynomial(
+        (base, factor, m2_coeff, m3_coeff, m4_coeff): (f64, f64, f64, f64, f64),
+        m: f64,
+    ) -> f64 {
+        base + factor * m + m2_coeff * m.powi(2) + m3_coeff * m.powi(3) + m4_coeff * m.powi(4)
+    }
+
+    #[test]
+    fn constants_before_year_1000_extend_the_constants_of_each_event() {
+        for year in [-1000, 0, 500, 1000] {
+            let m = year as f64 / 1000.0;
+            let early = polynomial(Beltane::julian_day_constants_before_year_1000(), m);
+            let late = polynomial(Beltane::julian_day_constants(), m - 2.0);
+
+            println!("{}: {} {}", year, early, late);
+            assert!((early - late).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn cross_quarter_days_are_the_same_with_both_methods() -> Result<(), Box<dyn Error>> {
+        for year in [-1000, 1000, 2024, 3000] {
+            let meeus = Samhain::for_year_with_method(year, CalculationMethod::Meeus)?;
+            let vsop87 = Samhain::for_year_with_method(year, CalculationMethod::TruncatedVsop87)?;
+
+            assert!((meeus.julian_ephemeris_day() - vsop87.julian_ephemeris_day()).abs() < 1e-6);
+        }
+        assert_eq!(
+            Imbolc::for_year(3001).err(),
+            Some(AnnualSolarEventError::YearOutOfRange(3001))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn cross_quarter_days_fall_between_the_equinoxes_and_solstices() -> Result<(), Box<dyn Error>> {
+        for year in 1900..=2100 {
+            let events = AnnualSolarEvents::for_year(year)?;
+            let cross_quarter = AnnualCrossQuarterEvents::for_year(year)?;
+            let instants = [
+                cross_quarter.imbolc().date_time(),
+                events.march_equinox().date_time(),
+                cross_quarter.beltane().date_time(),
+                events.june_solstice().date_time(),
+                cross_quarter.lughnasadh().date_time(),
+                events.september_equinox().date_time(),
+                cross_quarter.samhain().date_time(),
+                events.december_solstice().date_time(),
+            ];
+
+            assert_eq!(cross_quarter.year(), year);
+            assert!(instants.windows(2).all(|pair| pair[0] < pair[1]));
+            assert!((3..=5).contains(&cross_quarter.imbolc().date_time().day()));
+            assert!((4..=6).contains(&cross_quarter.beltane().date_time().day()));
+            assert!((6..=8).contains(&cross_quarter.lughnasadh().date_time().day()));
+            assert!((6..=8).contains(&cross_quarter.samhain().date_time().day()));
+        }
+        Ok(())
+    }
+}
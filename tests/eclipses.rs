@@ -0,0 +1,148 @@
+#[cfg(test)]
+mod tests {
+    use std::error::Error;
+
+    use chrono::{DateTime, TimeDelta, Utc};
+    use solar_calendar_events::*;
+
+    #[test]
+    fn eclipses_match_meeus_examples() -> Result<(), Box<dyn Error>> {
+        // Meeus, example 54.a: the partial solar eclipse of 1993 May 21.
+        let solar = Eclipses::for_year(1993)?.iter().copied().next().unwrap();
+        assert_eq!(solar.kind(), EclipseKind::Solar);
+        assert_eq!(solar.eclipse_type(), EclipseType::Partial);
+        assert!((solar.julian_ephemeris_day() - 2_449_129.097_9).abs() < 0.000_1);
+        assert!((solar.gamma() - 1.134_8).abs() < 0.000_1);
+        assert!((solar.magnitude() - 0.740).abs() < 0.001);
+
+        // Meeus, example 54.b: the penumbral lunar eclipse of 1973 June 15.
+        let lunar = Eclipses::for_year(1973)?
+            .iter()
+            .copied()
+            .find(|eclipse| eclipse.julian_day() > 2_441_840.0)
+            .unwrap();
+        assert_eq!(lunar.kind(), EclipseKind::Lunar);
+        assert_eq!(lunar.eclipse_type(), EclipseType::Penumbral);
+        assert!((lunar.julian_ephemeris_day() - 2_441_849.368_7).abs() < 0.000_1);
+        assert!((lunar.gamma() + 1.324_9).abs() < 0.000_1);
+        assert!((lunar.magnitude() - 0.462_5).abs() < 0.000_1);
+        Ok(())
+    }
+
+    #[test]
+    fn eclipses_match_published_eclipses() -> Result<(), Box<dyn Error>> {
+        // Greatest eclipse, magnitude and gamma from the NASA eclipse catalogs by Fred Espenak.
+        let published = [
+            (
+                EclipseType::Hybrid,
+                "2023-04-20T04:17:00Z",
+                1.013_2,
+                -0.395_6,
+            ),
+            (
+                EclipseType::Annular,
+                "2023-10-14T18:00:00Z",
+                0.952_0,
+                0.375_3,
+            ),
+            (
+                EclipseType::Partial,
+                "2023-10-28T20:14:00Z",
+                0.122_0,
+                0.947_2,
+            ),
+            (
+                EclipseType::Penumbral,
+                "2024-03-25T07:13:00Z",
+                0.957_7,
+                1.061_0,
+            ),
+            (EclipseType::Total, "2024-04-08T18:17:00Z", 1.056_6, 0.343_1),
+            (
+                EclipseType::Partial,
+                "2024-09-18T02:44:00Z",
+                0.084_8,
+                -0.979_2,
+            ),
+            (
+                EclipseType::Annular,
+                "2024-10-02T18:45:00Z",
+                0.932_6,
+                -0.350_9,
+            ),
+            (EclipseType::Total, "2025-03-14T06:59:00Z", 1.178_4, 0.348_5),
+            (
+                EclipseType::Partial,
+                "2025-03-29T10:48:00Z",
+                0.937_6,
+                1.040_5,
+            ),
+            (
+                EclipseType::Total,
+                "2025-09-07T18:12:00Z",
+                1.361_9,
+                -0.275_2,
+            ),
+            (
+                EclipseType::Partial,
+                "2025-09-21T19:43:00Z",
+                0.855_0,
+                -1.065_1,
+            ),
+        ];
+        // The penumbral lunar eclipse of 2023 May 5 grazes the penumbra, and the approximations of
+        // Meeus miss its magnitude of 0.9655 and gamma of -1.0350 by more than the tolerances, so
+        // only its type is compared.
+        let grazing: DateTime<Utc> = "2023-05-05T17:23:00Z".parse()?;
+        let start: DateTime<Utc> = "2023-04-01T00:00:00Z".parse()?;
+        let end: DateTime<Utc> = "2025-10-01T00:00:00Z".parse()?;
+        let eclipses = Eclipses::between(start, end)?;
+        assert_eq!(eclipses.len(), published.len() + 1);
+        assert!(eclipses
+            .iter()
+            .any(|eclipse| eclipse.eclipse_type() == EclipseType::Penumbral
+                && (eclipse.date_time() - grazing).abs() < TimeDelta::minutes(5)));
+
+        for (eclipse_type, date_time, magnitude, gamma) in published {
+            let date_time: DateTime<Utc> = date_time.parse()?;
+            let eclipse = eclipses
+                .iter()
+                .find(|eclipse| (eclipse.date_time() - date_time).abs() < TimeDelta::minutes(5))
+                .unwrap();
+
+            println!("{:?} {} {}", eclipse, eclipse.magnitude(), eclipse.gamma());
+            assert_eq!(eclipse.eclipse_type(), eclipse_type);
+            assert!((eclipse.magnitude() - magnitude).abs() < 0.01);
+            assert!((eclipse.gamma() - gamma).abs() < 0.005);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn eclipses_occur_at_new_and_full_moon() -> Result<(), Box<dyn Error>> {
+        for year in [-1000, 0, 1000, 2024, 3000] {
+            let eclipses = Eclipses::for_year(year)?;
+            let phases = LunarPhases::for_year(year)?;
+            let solar = eclipses
+                .iter()
+                .filter(|eclipse| eclipse.kind() == EclipseKind::Solar)
+                .count();
+
+            assert!((2..=5).contains(&solar));
+            assert!((4..=7).contains(&eclipses.len()));
+            for eclipse in &eclipses {
+                let phase = match eclipse.kind() {
+                    EclipseKind::Solar => LunarPhase::NewMoon,
+                    EclipseKind::Lunar => LunarPhase::FullMoon,
+                };
+                assert!(phases.iter().any(|event| event.phase() == phase
+                    && (event.julian_day() - eclipse.julian_day()).abs() < 0.1));
+            }
+        }
+        assert_eq!(
+            Eclipses::for_year(3001).err(),
+            Some(AnnualSolarEventError::YearOutOfRange(3001))
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,101 @@
+#[cfg(test)]
+mod tests {
+    use std::error::Error;
+
+    use chrono::{DateTime, TimeDelta, Utc};
+    use solar_calendar_events::*;
+
+    #[test]
+    fn lunar_phases_match_meeus_examples() -> Result<(), Box<dyn Error>> {
+        // Meeus, example 49.a: the new Moon of 1977 February.
+        let new_moon = LunarPhases::for_year(1977)?
+            .iter()
+            .find(|event| event.phase() == LunarPhase::NewMoon && event.julian_day() > 2_443_180.0)
+            .copied()
+            .unwrap();
+        assert!((new_moon.julian_ephemeris_day() - 2_443_192.651_18).abs() < 0.000_01);
+
+        // Meeus, example 49.b: the first last quarter of 2044.
+        let last_quarter = LunarPhases::for_year(2044)?
+            .iter()
+            .find(|event| event.phase() == LunarPhase::LastQuarter)
+            .copied()
+            .unwrap();
+        assert!((last_quarter.julian_ephemeris_day() - 2_467_636.491_86).abs() < 0.000_01);
+        Ok(())
+    }
+
+    #[test]
+    fn lunar_phases_match_published_instants() -> Result<(), Box<dyn Error>> {
+        // Published instants from the U.S. Naval Observatory, rounded to the minute.
+        let published = [
+            (LunarPhase::LastQuarter, "2024-01-04T03:30:00Z"),
+            (LunarPhase::NewMoon, "2024-01-11T11:57:00Z"),
+            (LunarPhase::FirstQuarter, "2024-01-18T03:52:00Z"),
+            (LunarPhase::FullMoon, "2024-01-25T17:54:00Z"),
+            (LunarPhase::NewMoon, "2024-02-09T22:59:00Z"),
+            (LunarPhase::FullMoon, "2024-02-24T12:30:00Z"),
+            (LunarPhase::NewMoon, "2024-03-10T09:00:00Z"),
+            (LunarPhase::FullMoon, "2024-03-25T07:00:00Z"),
+            (LunarPhase::NewMoon, "2024-04-08T18:21:00Z"),
+        ];
+        let phases = LunarPhases::for_year(2024)?;
+
+        for (phase, expected) in published {
+            let expected: DateTime<Utc> = expected.parse()?;
+            let event = phases
+                .iter()
+                .filter(|event| event.phase() == phase)
+                .min_by_key(|event| (event.date_time() - expected).abs())
+                .unwrap();
+
+            println!("{} {}", event.date_time(), expected);
+            assert!((event.date_time() - expected).abs() < TimeDelta::minutes(1));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn lunar_phases_follow_each_other() -> Result<(), Box<dyn Error>> {
+        for year in [-1000, 0, 1582, 2024, 3000] {
+            let phases = LunarPhases::for_year(year)?;
+            assert!((49..=51).contains(&phases.len()));
+
+            for (event, next) in phases.iter().zip(phases.iter().skip(1)) {
+                let next_phase = LunarPhase::ALL[(event.phase() as usize + 1) % 4];
+                let days = next.julian_day() - event.julian_day();
+
+                assert_eq!(next.phase(), next_phase);
+                assert!((5.5..9.0).contains(&days));
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn lunar_phases_between_instants() -> Result<(), Box<dyn Error>> {
+        let start: DateTime<Utc> = "2023-12-01T00:00:00Z".parse()?;
+        let end: DateTime<Utc> = "2025-02-01T00:00:00Z".parse()?;
+        let phases = LunarPhases::between(start, end)?;
+        let year = LunarPhases::for_year(2024)?;
+
+        assert!(phases
+            .iter()
+            .all(|event| event.date_time() >= start && event.date_time() < end));
+        assert!(year
+            .iter()
+            .all(|event| phases.iter().any(|other| other == event)));
+        assert_eq!(phases.len(), year.len() + 8);
+
+        assert!(LunarPhases::between(end, start)?.is_empty());
+        assert_eq!(
+            LunarPhases::between(start, "3001-01-01T00:00:00Z".parse()?).err(),
+            Some(AnnualSolarEventError::YearOutOfRange(3001))
+        );
+        assert_eq!(
+            LunarPhases::for_year(-1001).err(),
+            Some(AnnualSolarEventError::YearOutOfRange(-1001))
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,190 @@
+#[cfg(test)]
+mod tests {
+    use std::error::Error;
+
+    use chrono::{DateTime, NaiveDate, TimeDelta, Utc};
+    use solar_calendar_events::*;
+
+    /// Returns the altitude of the center of the Sun, in degrees, for an observer at an instant,
+    /// using the mean sidereal time of Meeus, equation 12.4. Without the nutation in right
+    /// ascension, it may be off by 0.005°.
+    fn altitude(observer: &Observer, date_time: DateTime<Utc>) -> Result<f64, Box<dyn Error>> {
+        let sun = SunPosition::at(date_time)?;
+        let days = date_time.to_julian_day() - 2_451_545.0;
+        let hour_angle = (280.460_618_37 + 360.985_647_366_29 * days + observer.longitude
+            - sun.right_ascension())
+        .to_radians();
+        let (latitude, declination) = (
+            observer.latitude.to_radians(),
+            sun.declination().to_radians(),
+        );
+
+        Ok((latitude.sin() * declination.sin()
+            + latitude.cos() * declination.cos() * hour_angle.cos())
+        .asin()
+        .to_degrees())
+    }
+
+    #[test]
+    fn sunrise_and_sunset_match_published_times() -> Result<(), Box<dyn Error>> {
+        // Published times from timeanddate.com, rounded to the minute.
+        let published = [
+            (
+                (40.712_8, -74.006_0),
+                (2024, 3, 20),
+                "2024-03-20T10:58:00Z",
+                "2024-03-20T23:09:00Z",
+            ),
+            (
+                (-33.868_8, 151.209_3),
+                (2024, 1, 1),
+                "2023-12-31T18:47:00Z",
+                "2024-01-01T09:09:00Z",
+            ),
+            (
+                (35.68, 139.77),
+                (2024, 6, 21),
+                "2024-06-20T19:25:00Z",
+                "2024-06-21T10:00:00Z",
+            ),
+        ];
+
+        for ((latitude, longitude), (year, month, day), sunrise, sunset) in published {
+            let observer = Observer::new(latitude, longitude, 0.0);
+            let date = NaiveDate::from_ymd_opt(year, month, day).unwrap();
+            let solar_day = observer.solar_day(date)?;
+            let sunrise: DateTime<Utc> = sunrise.parse()?;
+            let sunset: DateTime<Utc> = sunset.parse()?;
+
+            println!("{:?}", solar_day);
+            assert_eq!(solar_day.date(), date);
+            assert!((solar_day.sunrise().unwrap() - sunrise).abs() < TimeDelta::minutes(2));
+            assert!((solar_day.sunset().unwrap() - sunset).abs() < TimeDelta::minutes(2));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn sun_is_on_the_horizon_at_sunrise_and_sunset() -> Result<(), Box<dyn Error>> {
+        for (latitude, longitude) in [(0.0, 0.0), (51.48, 0.0), (-45.0, 170.0), (64.0, -150.0)] {
+            let observer = Observer::new(latitude, longitude, 0.0);
+            for month in 1..=12 {
+                let date = NaiveDate::from_ymd_opt(2024, month, 15).unwrap();
+                let solar_day = observer.solar_day(date)?;
+
+                assert!(
+                    (altitude(&observer, solar_day.sunrise().unwrap())? + 0.833_3).abs() < 0.01
+                );
+                assert!((altitude(&observer, solar_day.sunset().unwrap())? + 0.833_3).abs() < 0.01);
+                assert!(solar_day.sunrise().unwrap() < solar_day.transit());
+                assert!(solar_day.transit() < solar_day.sunset().unwrap());
+                assert!(altitude(&observer, solar_day.transit())? > 0.0);
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn sun_may_never_rise_or_set() -> Result<(), Box<dyn Error>> {
+        let tromso = Observer::new(69.65, 18.96, 0.0);
+        let midsummer = tromso.solar_day(NaiveDate::from_ymd_opt(2024, 6, 20).unwrap())?;
+        let midwinter = tromso.solar_day(NaiveDate::from_ymd_opt(2024, 12, 20).unwrap())?;
+
+        assert_eq!(midsummer.sunrise_sunset(), RiseSet::AlwaysAbove);
+        assert_eq!(midsummer.sunrise(), None);
+        assert_eq!(midwinter.sunrise_sunset(), RiseSet::AlwaysBelow);
+        assert_eq!(midwinter.sunset(), None);
+
+        let south_pole = Observer::new(-90.0, 0.0, 2_835.0);
+        let solar_day = south_pole.solar_day(NaiveDate::from_ymd_opt(2024, 1, 1).unwrap())?;
+        assert_eq!(solar_day.sunrise_sunset(), RiseSet::AlwaysAbove);
+        Ok(())
+    }
+
+    #[test]
+    fn elevation_lengthens_the_day() -> Result<(), Box<dyn Error>> {
+        let date = NaiveDate::from_ymd_opt(2024, 9, 1).unwrap();
+        let sea_level = Observer::new(46.0, 7.0, 0.0).solar_day(date)?;
+        let mountain = Observer::new(46.0, 7.0, 2_500.0).solar_day(date)?;
+
+        assert!(mountain.sunrise() < sea_level.sunrise());
+        assert!(mountain.sunset() > sea_level.sunset());
+        assert_eq!(
+            Observer::default()
+                .solar_day(NaiveDate::from_ymd_opt(6_001, 1, 1).unwrap())
+                .err(),
+            Some(AnnualSolarEventError::YearOutOfRange(6_001))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn twilight_is_at_its_depression() -> Result<(), Box<dyn Error>> {
+        let observer = Observer::new(-24.63, -70.40, 0.0);
+        let date = NaiveDate::from_ymd_opt(2024, 5, 1).unwrap();
+        let sunrise = observer.solar_day(date)?.sunrise().unwrap();
+        let mut previous = None;
+
+        for twilight in [
+            Twilight::Astronomical,
+            Twilight::Nautical,
+            Twilight::Civil,
+            Twilight::Custom(3.0),
+        ] {
+            let dawn = observer.dawn(date, twilight)?.unwrap();
+            let dusk = observer.dusk(date, twilight)?.unwrap();
+
+            assert!((altitude(&observer, dawn)? + twilight.depression()).abs() < 0.01);
+            assert!((altitude(&observer, dusk)? + twilight.depression()).abs() < 0.01);
+            assert!(previous < Some(dawn) && dawn < sunrise);
+            previous = Some(dawn);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn twilight_may_never_end() -> Result<(), Box<dyn Error>> {
+        let stockholm = Observer::new(59.33, 18.07, 0.0);
+        let midsummer = NaiveDate::from_ymd_opt(2024, 6, 20).unwrap();
+        let midwinter = NaiveDate::from_ymd_opt(2024, 12, 20).unwrap();
+
+        assert!(stockholm.dusk(midsummer, Twilight::Civil)?.is_some());
+        assert_eq!(
+            stockholm.twilight(midsummer, Twilight::Nautical)?,
+            RiseSet::AlwaysAbove
+        );
+        assert_eq!(stockholm.dawn(midsummer, Twilight::Astronomical)?, None);
+        assert!(stockholm.dawn(midwinter, Twilight::Astronomical)?.is_some());
+
+        let svalbard = Observer::new(78.22, 15.65, 0.0);
+        assert_eq!(
+            svalbard.twilight(midwinter, Twilight::Civil)?,
+            RiseSet::AlwaysBelow
+        );
+        assert_eq!(
+            svalbard.twilight(midwinter, Twilight::Custom(12.0))?,
+            svalbard.twilight(midwinter, Twilight::Nautical)?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn astronomical_darkness_through_a_season() -> Result<(), Box<dyn Error>> {
+        let events = AnnualSolarEvents::for_year(2024)?;
+        let start = events.june_solstice().date_time().date_naive();
+        let end = events.september_equinox().date_time().date_naive();
+        let observer = Observer::new(19.82, -155.47, 4_200.0);
+
+        for date in start.iter_days().take_while(|date| *date < end) {
+            let dusk = observer.dusk(date, Twilight::Astronomical)?.unwrap();
+            let dawn = observer
+                .dawn(date.succ_opt().unwrap(), Twilight::Astronomical)?
+                .unwrap();
+            let darkness = dawn - dusk;
+
+            println!("{date} {dusk} {dawn} {darkness}");
+            assert!(darkness > TimeDelta::minutes(450) && darkness < TimeDelta::minutes(570));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,100 @@
+#[cfg(test)]
+mod tests {
+    use std::error::Error;
+
+    use chrono::TimeDelta;
+    use solar_calendar_events::*;
+
+    #[test]
+    fn polar_periods_end_where_the_sun_rises_and_sets() -> Result<(), Box<dyn Error>> {
+        for (latitude, longitude) in [
+            (69.65, 18.96),
+            (78.22, 15.65),
+            (-70.0, 0.0),
+            (-77.85, 166.67),
+        ] {
+            let observer = Observer::new(latitude, longitude, 0.0);
+            let periods = PolarPeriods::for_year(&observer, 2024)?;
+            assert_eq!(periods.iter().count(), 2);
+
+            for period in periods.iter() {
+                let state = match period.kind() {
+                    PolarPeriodKind::MidnightSun => RiseSet::AlwaysAbove,
+                    PolarPeriodKind::PolarNight => RiseSet::AlwaysBelow,
+                };
+                let before = period.first_date() - TimeDelta::days(1);
+                let after = period.last_date() + TimeDelta::days(1);
+
+                println!("{:?}", period);
+                assert_eq!(
+                    observer.solar_day(period.first_date())?.sunrise_sunset(),
+                    state
+                );
+                assert_eq!(
+                    observer.solar_day(period.last_date())?.sunrise_sunset(),
+                    state
+                );
+                assert!(observer.solar_day(before)?.sunrise().is_some());
+                assert!(observer.solar_day(after)?.sunrise().is_some());
+                assert_eq!(
+                    period.days_before_solstice() + period.days_after_solstice() + 1,
+                    period.days()
+                );
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn polar_periods_surround_the_solstices() -> Result<(), Box<dyn Error>> {
+        let events = AnnualSolarEvents::for_year(2024)?;
+        let svalbard = PolarPeriods::for_year(&Observer::new(78.22, 15.65, 0.0), 2024)?;
+        let midnight_sun = svalbard.midnight_sun().unwrap();
+        let polar_night = svalbard.polar_night().unwrap();
+
+        assert_eq!(svalbard.june(), Some(midnight_sun));
+        assert_eq!(midnight_sun.solstice(), events.june_solstice().date_time());
+        assert_eq!(svalbard.december(), Some(polar_night));
+        assert_eq!(
+            polar_night.solstice(),
+            events.december_solstice().date_time()
+        );
+        assert_eq!(polar_night.last_date().to_string(), "2025-02-14");
+        for period in svalbard.iter() {
+            assert!(period.days_before_solstice() > 0 && period.days_after_solstice() > 0);
+        }
+
+        let antarctica = PolarPeriods::for_year(&Observer::new(-70.0, 0.0, 0.0), 2024)?;
+        assert_eq!(
+            antarctica.june().unwrap().kind(),
+            PolarPeriodKind::PolarNight
+        );
+        assert_eq!(
+            antarctica.december().unwrap().kind(),
+            PolarPeriodKind::MidnightSun
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn polar_periods_at_the_poles_and_below_the_polar_circles() -> Result<(), Box<dyn Error>> {
+        let north_pole = PolarPeriods::for_year(&Observer::new(90.0, 0.0, 0.0), 2024)?;
+        let midnight_sun = north_pole.midnight_sun().unwrap();
+        let polar_night = north_pole.polar_night().unwrap();
+
+        assert!((185..=195).contains(&midnight_sun.days()));
+        assert_eq!(
+            midnight_sun.last_date().succ_opt(),
+            Some(polar_night.first_date())
+        );
+
+        let oslo = PolarPeriods::for_year(&Observer::new(59.91, 10.75, 0.0), 2024)?;
+        assert_eq!(oslo.iter().count(), 0);
+        assert_eq!(oslo.year(), 2024);
+        assert_eq!(
+            PolarPeriods::for_year(&Observer::default(), 3001).err(),
+            Some(AnnualSolarEventError::YearOutOfRange(3001))
+        );
+        Ok(())
+    }
+}
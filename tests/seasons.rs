@@ -0,0 +1,162 @@
+#[cfg(test)]
+mod tests {
+    use std::error::Error;
+
+    use chrono::{DateTime, TimeDelta, Utc};
+    use solar_calendar_events::*;
+
+    #[test]
+    fn seasons_follow_the_events_of_the_year() -> Result<(), Box<dyn Error>> {
+        let events = AnnualSolarEvents::for_year(2024)?;
+        let northern = [
+            SeasonKind::Spring,
+            SeasonKind::Summer,
+            SeasonKind::Autumn,
+            SeasonKind::Winter,
+        ];
+        let southern = [
+            SeasonKind::Autumn,
+            SeasonKind::Winter,
+            SeasonKind::Spring,
+            SeasonKind::Summer,
+        ];
+
+        for (index, event) in events.iter().enumerate() {
+            let date_time = event.date_time() + TimeDelta::days(1);
+            let season = Season::at(date_time, Hemisphere::Northern)?;
+            println!(
+                "{}: {} until {}",
+                season.kind(),
+                event.date_time(),
+                season.end().date_time()
+            );
+
+            assert_eq!(season.kind(), northern[index]);
+            assert_eq!(season.start(), event);
+            assert_eq!(season.hemisphere(), Hemisphere::Northern);
+            assert_eq!(season.date_time(), date_time);
+            assert_eq!(
+                Season::at(date_time, Hemisphere::Southern)?.kind(),
+                southern[index]
+            );
+            assert!((88..=94).contains(&season.duration().num_days()));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn winter_spans_the_end_of_the_year() -> Result<(), Box<dyn Error>> {
+        let solstice = SolarEvent::for_year(SolarEventKind::DecemberSolstice, 2023)?;
+        let equinox = SolarEvent::for_year(SolarEventKind::MarchEquinox, 2024)?;
+
+        for date_time in [
+            "2023-12-31T23:59:59Z",
+            "2024-01-01T00:00:00Z",
+            "2024-03-19T00:00:00Z",
+        ] {
+            let season = Season::at(date_time.parse()?, Hemisphere::Northern)?;
+            assert_eq!(season.kind(), SeasonKind::Winter);
+            assert_eq!(season.start(), solstice);
+            assert_eq!(season.end(), equinox);
+        }
+
+        // A season includes the instant of its first event, but not of its last.
+        let season = Season::at(solstice.date_time(), Hemisphere::Southern)?;
+        assert_eq!(season.kind(), SeasonKind::Summer);
+        assert_eq!(season.start(), solstice);
+        assert_eq!(season.fraction_elapsed(), 0.0);
+        assert_eq!(season.time_remaining(), season.duration());
+
+        let season = Season::at(equinox.date_time(), Hemisphere::Southern)?;
+        assert_eq!(season.kind(), SeasonKind::Autumn);
+        assert_eq!(season.start(), equinox);
+        Ok(())
+    }
+
+    #[test]
+    fn progress_adds_up() -> Result<(), Box<dyn Error>> {
+        let mut date_time: DateTime<Utc> = "2024-01-01T00:00:00Z".parse()?;
+        let end: DateTime<Utc> = "2025-01-01T00:00:00Z".parse()?;
+        while date_time < end {
+            let season = Season::at(date_time, Hemisphere::Southern)?;
+
+            assert_eq!(
+                season.time_elapsed() + season.time_remaining(),
+                season.duration()
+            );
+            assert!((0.0..1.0).contains(&season.fraction_elapsed()));
+            let expected =
+                season.time_elapsed().num_seconds() as f64 / season.duration().num_seconds() as f64;
+            assert!((season.fraction_elapsed() - expected).abs() < 1e-6);
+            date_time += TimeDelta::hours(77);
+        }
+
+        // Half way between the June Solstice and the September Equinox of 2024.
+        let events = AnnualSolarEvents::for_year(2024)?;
+        let start = events.june_solstice().date_time();
+        let end = events.september_equinox().date_time();
+        let season = Season::at(start + (end - start) / 2, Hemisphere::Northern)?;
+        assert!((season.fraction_elapsed() - 0.5).abs() < 1e-9);
+
+        assert_eq!(
+            Season::at("3000-12-25T00:00:00Z".parse()?, Hemisphere::Northern).err(),
+            Some(AnnualSolarEventError::YearOutOfRange(3001))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn seasonal_names_flip_between_hemispheres() -> Result<(), Box<dyn Error>> {
+        use Hemisphere::*;
+        use SeasonalEvent::*;
+        use SolarEventKind::*;
+
+        let names = [
+            (MarchEquinox, SpringEquinox, AutumnEquinox),
+            (JuneSolstice, SummerSolstice, WinterSolstice),
+            (SeptemberEquinox, AutumnEquinox, SpringEquinox),
+            (DecemberSolstice, WinterSolstice, SummerSolstice),
+        ];
+        let events = AnnualSolarEvents::for_year(2024)?;
+
+        for (kind, northern, southern) in names {
+            assert_eq!(kind.seasonal_event(Northern), northern);
+            assert_eq!(kind.seasonal_event(Southern), southern);
+            assert_eq!(northern.kind(Northern), kind);
+            assert_eq!(southern.kind(Southern), kind);
+            assert_eq!(kind.season_start(Northern), northern.season_start());
+            assert_eq!(kind.season_start(Southern), southern.season_start());
+
+            assert_eq!(events.seasonal(northern, Northern), events.get(kind));
+            assert_eq!(events.seasonal(southern, Southern), events.get(kind));
+            assert_eq!(southern.for_year(Southern, 2024)?, events.get(kind));
+
+            // The season at the event is the one it starts.
+            let date_time = events.get(kind).date_time();
+            assert_eq!(
+                Season::at(date_time, Southern)?.kind(),
+                southern.season_start()
+            );
+        }
+        assert_eq!(Hemisphere::from_latitude(-33.87), Southern);
+        assert_eq!(Hemisphere::from_latitude(0.0), Northern);
+        Ok(())
+    }
+
+    #[test]
+    fn seasonal_names_parse() {
+        for event in SeasonalEvent::ALL {
+            assert_eq!(event.name().parse(), Ok(event));
+            assert_eq!(event.to_string().to_lowercase().parse(), Ok(event));
+        }
+        assert_eq!("Vernal Equinox".parse(), Ok(SeasonalEvent::SpringEquinox));
+        assert_eq!("autumnal_equinox".parse(), Ok(SeasonalEvent::AutumnEquinox));
+        assert_eq!("fall-equinox".parse(), Ok(SeasonalEvent::AutumnEquinox));
+        assert_eq!(
+            "Winter Equinox".parse::<SeasonalEvent>(),
+            Err(AnnualSolarEventError::UnknownSolarEventKind(
+                "Winter Equinox".to_string()
+            ))
+        );
+    }
+}
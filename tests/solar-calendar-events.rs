@@ -2,19 +2,180 @@
 mod tests {
     use std::error::Error;
 
-    use chrono::{DateTime, Datelike, TimeDelta, Utc};
+    use chrono::{DateTime, Datelike, NaiveDateTime, TimeDelta, Utc};
     use solar_calendar_events::*;
 
     #[test]
-    fn test_events_before_1900_or_after_2100() {
-        assert!(MarchEquinox::for_year(1899).is_err());
-        assert!(MarchEquinox::for_year(2101).is_err());
-        assert!(JuneSolstice::for_year(1899).is_err());
-        assert!(JuneSolstice::for_year(2101).is_err());
-        assert!(SeptemberEquinox::for_year(1899).is_err());
-        assert!(SeptemberEquinox::for_year(2101).is_err());
-        assert!(DecemberSolstice::for_year(1899).is_err());
-        assert!(DecemberSolstice::for_year(2101).is_err());
+    fn test_events_before_minus_1000_or_after_3000() {
+        assert!(MarchEquinox::for_year(-1001).is_err());
+        assert!(MarchEquinox::for_year(3001).is_err());
+        assert!(JuneSolstice::for_year(-1001).is_err());
+        assert!(JuneSolstice::for_year(3001).is_err());
+        assert!(SeptemberEquinox::for_year(-1001).is_err());
+        assert!(SeptemberEquinox::for_year(3001).is_err());
+        assert!(DecemberSolstice::for_year(-1001).is_err());
+        assert!(DecemberSolstice::for_year(3001).is_err());
+        assert_eq!(
+            AnnualSolarEvents::for_year(3001).err(),
+            Some(AnnualSolarEventError::YearOutOfRange(3001))
+        );
+    }
+
+    #[test]
+    fn test_events_between_minus_1000_and_3000() {
+        assert_eq!(MarchEquinox::year_range(), -1000..=3000);
+
+        for year in [-1000, -1, 0, 1, 999, 1000, 1899, 2101, 3000] {
+            let events = AnnualSolarEvents::for_year(year).unwrap();
+
+            assert_eq!(events.year(), year);
+            assert_eq!(events.march_equinox().date_time().year(), year);
+            assert_eq!(events.december_solstice().date_time().year(), year);
+        }
+    }
+
+    #[test]
+    fn tables_27_a_and_27_b_agree_around_year_1000() {
+        // Both tables are valid in year 1000, so the length of the year between the last event
+        // from Table 27.A and the first event from Table 27.B should be a normal tropical year.
+        let before = MarchEquinox::for_year(999).unwrap().julian_day();
+        let after = MarchEquinox::for_year(1000).unwrap().julian_day();
+        let tropical_year = after - before;
+
+        println!("tropical year across the tables: {}", tropical_year);
+        assert!((tropical_year - 365.2422).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_year_range_of_calculation_methods() {
+        assert_eq!(CalculationMethod::default(), CalculationMethod::Meeus);
+        assert_eq!(CalculationMethod::Meeus.year_range(), -1000..=3000);
+        assert_eq!(
+            CalculationMethod::TruncatedVsop87.year_range(),
+            -2000..=6000
+        );
+
+        assert!(
+            MarchEquinox::for_year_with_method(-2000, CalculationMethod::TruncatedVsop87).is_ok()
+        );
+        assert!(
+            DecemberSolstice::for_year_with_method(6000, CalculationMethod::TruncatedVsop87)
+                .is_ok()
+        );
+        assert_eq!(
+            JuneSolstice::for_year_with_method(6001, CalculationMethod::TruncatedVsop87).err(),
+            Some(AnnualSolarEventError::YearOutOfRange(6001))
+        );
+        assert_eq!(
+            SeptemberEquinox::for_year_with_method(6000, CalculationMethod::Meeus).err(),
+            Some(AnnualSolarEventError::YearOutOfRange(6000))
+        );
+    }
+
+    #[test]
+    fn events_fall_in_their_year_at_both_ends_of_the_range() {
+        for method in [CalculationMethod::Meeus, CalculationMethod::TruncatedVsop87] {
+            let range = method.year_range();
+            for year in [*range.start(), *range.end()] {
+                for kind in SolarEventKind::ALL {
+                    let event = SolarEvent::for_year_with_method(kind, year, method).unwrap();
+
+                    println!("{:?} {:?} {}: {}", method, kind, year, event.date_time());
+                    assert_eq!(event.year(), year);
+                    assert_eq!(event.date_time().year(), year);
+                }
+            }
+        }
+    }
+
+    fn match_vsop87_to_meeus<T: AnnualSolarEvent>(years: impl Iterator<Item = i32>, max_diff: f64) {
+        for year in years {
+            let meeus = T::for_year_with_method(year, CalculationMethod::Meeus).unwrap();
+            let vsop87 = T::for_year_with_method(year, CalculationMethod::TruncatedVsop87).unwrap();
+            let diff = (vsop87.julian_ephemeris_day() - meeus.julian_ephemeris_day()) * 86_400.0;
+
+            println!(
+                "{}: {} - {} = {}",
+                year,
+                vsop87.julian_ephemeris_day(),
+                meeus.julian_ephemeris_day(),
+                diff
+            );
+            assert_eq!(vsop87.year(), year);
+            assert!(diff.abs() <= max_diff);
+        }
+    }
+
+    // Meeus states that the periodic terms of Table 27.C give results within 51 seconds of
+    // the full VSOP87 theory between 1951 and 2050. Allow a few more seconds for the truncated
+    // theory.
+    #[test]
+    fn vsop87_events_are_within_a_minute_of_meeus_events() {
+        match_vsop87_to_meeus::<MarchEquinox>(1951..=2050, 60.0);
+        match_vsop87_to_meeus::<JuneSolstice>(1951..=2050, 60.0);
+        match_vsop87_to_meeus::<SeptemberEquinox>(1951..=2050, 60.0);
+        match_vsop87_to_meeus::<DecemberSolstice>(1951..=2050, 60.0);
+    }
+
+    // Further from the present the polynomials of Tables 27.A and 27.B are less accurate, but
+    // stay within a few minutes of the VSOP87 instants over their whole range.
+    #[test]
+    fn vsop87_events_are_within_a_few_minutes_of_meeus_events_in_all_years() {
+        match_vsop87_to_meeus::<MarchEquinox>((-1000..=3000).step_by(7), 150.0);
+        match_vsop87_to_meeus::<JuneSolstice>((-1000..=3000).step_by(7), 150.0);
+        match_vsop87_to_meeus::<SeptemberEquinox>((-1000..=3000).step_by(7), 150.0);
+        match_vsop87_to_meeus::<DecemberSolstice>((-1000..=3000).step_by(7), 150.0);
+    }
+
+    #[test]
+    fn june_solstice_of_1962_with_truncated_vsop87_is_within_seconds_of_the_full_theory() {
+        // Meeus gives 1962 June 21, 21h24m42s TD as the instant of the solstice from the full
+        // VSOP87 theory.
+        let event =
+            JuneSolstice::for_year_with_method(1962, CalculationMethod::TruncatedVsop87).unwrap();
+        let seconds = (event.julian_ephemeris_day() - 2437836.5) * 86_400.0;
+        let diff = seconds - (21.0 * 3_600.0 + 24.0 * 60.0 + 42.0);
+
+        println!("{} seconds from the full theory", diff);
+        assert!(diff.abs() < 5.0);
+    }
+
+    #[test]
+    fn delta_t_matches_espenak_and_meeus() {
+        // Values from the NASA Five Millennium Canon of Solar Eclipses.
+        assert!((delta_t(-500.0) - 17_203.7).abs() < 0.1);
+        assert!((delta_t(1000.0) - 1_574.2).abs() < 0.1);
+        assert!((delta_t(1900.0) - -2.79).abs() < 0.01);
+        assert!((delta_t(1950.0) - 29.07).abs() < 0.01);
+        assert!((delta_t(2000.0) - 63.86).abs() < 0.01);
+        assert!((delta_t(2050.0) - 93.0).abs() < 1.0);
+    }
+
+    #[test]
+    fn delta_t_is_continuous_between_polynomials() {
+        for year in [
+            -500.0, 500.0, 1600.0, 1700.0, 1800.0, 1860.0, 1900.0, 1920.0, 1941.0, 1961.0, 1986.0,
+            2005.0, 2050.0, 2150.0,
+        ] {
+            let before = delta_t(year - 0.001);
+            let after = delta_t(year);
+
+            println!("{}: {} - {}", year, before, after);
+            assert!((after - before).abs() < 5.0);
+        }
+    }
+
+    #[test]
+    fn events_differ_by_delta_t_between_time_scales() {
+        let event = SeptemberEquinox::for_year(1987).unwrap();
+        let diff = (event.julian_ephemeris_day() - event.julian_day()) * 86_400.0;
+
+        println!("ΔT = {}", diff);
+        assert!((diff - delta_t(1987.7)).abs() < 0.05);
+        assert_eq!(
+            event.date_time(),
+            DateTime::from_julian_day(event.julian_day()).unwrap()
+        );
     }
 
     #[test]
@@ -36,12 +197,12 @@ mod tests {
     }
 
     #[test]
-    fn date_time_from_julian_day_number_2415435_452_is_1901_02_19_22_50_53() {
+    fn date_time_from_julian_day_number_2415435_452_is_1901_02_19_22_50_52_8() {
         let result = DateTime::from_julian_day(2415435.452).unwrap();
-        let time = DateTime::parse_from_rfc3339("1901-02-19T22:50:53Z").unwrap();
+        let time = DateTime::parse_from_rfc3339("1901-02-19T22:50:52.8Z").unwrap();
 
         println!("{:?} - {:?}", result, time);
-        assert_eq!(result, time);
+        assert!((result - time.to_utc()).num_microseconds().unwrap().abs() <= 40);
     }
 
     #[test]
@@ -53,6 +214,134 @@ mod tests {
         assert_eq!(result, time);
     }
 
+    #[test]
+    fn date_time_from_julian_day_number_keeps_sub_second_precision() {
+        // 2000-01-01T12:00:00.010Z, one sample of a 100 Hz sensor after noon.
+        let result = DateTime::from_julian_day(2451545.0 + 0.01 / 86_400.0).unwrap();
+        let time = DateTime::parse_from_rfc3339("2000-01-01T12:00:00.010Z").unwrap();
+
+        println!("{:?} - {:?}", result, time);
+        assert!((result - time.to_utc()).num_microseconds().unwrap().abs() <= 40);
+    }
+
+    #[test]
+    fn date_time_from_julian_day_number_rounds_to_the_next_day() {
+        let result = DateTime::from_julian_day(2451545.5 - 1e-15).unwrap();
+        let time = DateTime::parse_from_rfc3339("2000-01-02T00:00:00Z").unwrap();
+
+        assert_eq!(result, time);
+    }
+
+    #[test]
+    fn date_time_from_julian_day_number_round_trips_across_the_supported_range() {
+        let mut time = NaiveDateTime::parse_from_str("-1000-01-01 00:00:00.123", "%F %T%.f")
+            .unwrap()
+            .and_utc();
+        let end = DateTime::parse_from_rfc3339("3000-12-31T23:59:59.99Z")
+            .unwrap()
+            .to_utc();
+
+        while time < end {
+            let result = DateTime::from_julian_day(time.to_julian_day()).unwrap();
+            let diff = (result - time).num_microseconds().unwrap();
+
+            // Both the Julian Day and its conversion are rounded to the resolution of an f64,
+            // which is about 40 µs in this range.
+            assert!(diff.abs() <= 80, "{} - {} = {} µs", result, time, diff);
+            time += TimeDelta::milliseconds(1_234_567_891);
+        }
+    }
+
+    fn to_five_decimals_via_string(value: f64) -> f64 {
+        format!("{:.5}", value).parse().unwrap()
+    }
+
+    // The Meeus instants are rounded to five decimals without going through a string, so they
+    // must be left unchanged by formatting them to five decimals and parsing them back.
+    fn match_julian_ephemeris_days_to_rounding_through_a_string<T: AnnualSolarEvent>() {
+        for year in CalculationMethod::Meeus.year_range() {
+            let julian_ephemeris_day = T::for_year(year).unwrap().julian_ephemeris_day();
+
+            assert_eq!(
+                julian_ephemeris_day,
+                to_five_decimals_via_string(julian_ephemeris_day),
+                "{}: {:?}",
+                year,
+                julian_ephemeris_day
+            );
+        }
+    }
+
+    #[test]
+    fn julian_ephemeris_days_match_rounding_through_a_string() {
+        match_julian_ephemeris_days_to_rounding_through_a_string::<MarchEquinox>();
+        match_julian_ephemeris_days_to_rounding_through_a_string::<JuneSolstice>();
+        match_julian_ephemeris_days_to_rounding_through_a_string::<SeptemberEquinox>();
+        match_julian_ephemeris_days_to_rounding_through_a_string::<DecemberSolstice>();
+    }
+
+    fn utc(s: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(s).unwrap().to_utc()
+    }
+
+    #[test]
+    fn julian_day_from_date_time_at_j2000() {
+        let time = utc("2000-01-01T12:00:00Z");
+
+        assert_eq!(time.to_julian_day(), 2451545.0);
+        assert_eq!(time.to_modified_julian_day(), 51544.5);
+        assert_eq!(time.julian_centuries_since_j2000(), 0.0);
+        assert_eq!(time.naive_utc().to_julian_day(), 2451545.0);
+    }
+
+    #[test]
+    fn julian_day_from_date_time_matches_meeus_examples() {
+        // Example 7.a: 1957 October 4.81, the launch of Sputnik 1.
+        let sputnik = utc("1957-10-04T19:26:24Z").to_julian_day();
+        assert!((sputnik - 2436116.31).abs() < 1e-9);
+
+        // Example 7.b: 333 January 27.5 in the Julian calendar, which is January 28 in the
+        // proleptic Gregorian calendar used by chrono.
+        assert_eq!(utc("0333-01-28T12:00:00Z").to_julian_day(), 1842713.0);
+
+        // The first day of the Gregorian calendar, and the last day of the Julian calendar, which
+        // is 1582-10-04 in the Julian calendar and 1582-10-14 in the proleptic Gregorian calendar.
+        assert_eq!(utc("1582-10-15T00:00:00Z").to_julian_day(), 2299160.5);
+        assert_eq!(utc("1582-10-14T00:00:00Z").to_julian_day(), 2299159.5);
+    }
+
+    #[test]
+    fn julian_centuries_since_j2000_from_date_time() {
+        // Example 12.a: 1987 April 10, 0h UT.
+        let t = utc("1987-04-10T00:00:00Z").julian_centuries_since_j2000();
+
+        assert!((t - -0.127_296_372_348).abs() < 1e-12);
+    }
+
+    #[test]
+    fn julian_day_round_trips_through_date_time() {
+        for julian_day in [
+            1355807.5,
+            1721423.5,
+            1842713.0,
+            2299159.5,
+            2299160.5,
+            2415435.452,
+            2451545.0,
+            2460482.369522225,
+            2816866.22794,
+        ] {
+            let time = DateTime::from_julian_day(julian_day).unwrap();
+            let naive = NaiveDateTime::from_julian_day(julian_day).unwrap();
+            let diff = (time.to_julian_day() - julian_day) * 86_400e6;
+
+            println!("{} -> {} -> {} µs", julian_day, time, diff);
+            assert!(diff.abs() <= 40.0);
+            assert_eq!(naive, time.naive_utc());
+            assert_eq!(naive.to_julian_day(), time.to_julian_day());
+        }
+    }
+
     // The following tests are some actual observations of the March equinox,
     // here we aim to be within 10 minutes.
     fn match_march_equinox_to_observation(observation_str: &str) {
@@ -88,6 +377,18 @@ mod tests {
         match_march_equinox_to_observation("2010-03-20T17:33:00Z");
     }
 
+    // The NASA tables advance the March equinox by exactly their "tropical year" of 365.2425
+    // days, while the mean interval between March equinoxes is 365.242374 days (Meeus, chapter
+    // 27), so the tables drift later by 0.000126 days a year. The other events are placed
+    // relative to the March equinox and drift with it. Returns the difference from the table
+    // with that drift, counted from the middle of the table, and the average difference removed.
+    fn nasa_residual(year: i32, diff: i64, average_difference: f64) -> f64 {
+        const DRIFT_SECONDS_PER_YEAR: f64 = (365.242_374 - 365.242_5) * 86_400.0;
+        const MIDDLE_YEAR: f64 = (1900.0 + 2089.0) / 2.0;
+
+        diff as f64 - average_difference - DRIFT_SECONDS_PER_YEAR * (year as f64 - MIDDLE_YEAR)
+    }
+
     #[test]
     fn march_equinox_date_time_is_close_to_nasa_date_time() {
         let nasa_march_equinoxes_1900_2089_strs = vec![
@@ -305,9 +606,12 @@ mod tests {
             let diff = (event_time - nasa_calculation).num_seconds();
 
             println!("{} - {} = {}", event_time, nasa_calculation, diff);
-            // The difference should be less than 20 minutes. Note that the NASA
-            // numbers are not observations but another set of calculations.
-            assert!(diff.abs() <= 1200);
+            // The difference should be less than 20 minutes once the drift of the
+            // table is removed. Note that the NASA numbers are not observations but
+            // another set of calculations, based on a fixed tropical year without
+            // any periodic terms.
+            let residual = nasa_residual(nasa_calculation.year(), diff, average_difference);
+            assert!(residual.abs() <= 1200.0);
         }
     }
 
@@ -527,11 +831,12 @@ mod tests {
             let diff = (event_time - nasa_calculation).num_seconds();
 
             println!("{} - {} = {}", event_time, nasa_calculation, diff);
-            // The difference should be less than 24 minutes. Note that the NASA
-            // numbers are not observations but another set of calculations.
-            // The accurracy of the SummerSolstice calculations seem to be just
-            // a bit worse than the other calculations.
-            assert!(diff.abs() <= 1440);
+            // The difference should be less than 20 minutes once the drift of the
+            // table is removed. Note that the NASA numbers are not observations but
+            // another set of calculations, based on a fixed tropical year without
+            // any periodic terms.
+            let residual = nasa_residual(nasa_calculation.year(), diff, average_difference);
+            assert!(residual.abs() <= 1200.0);
         }
     }
 
@@ -752,9 +1057,12 @@ mod tests {
             let diff = (event_time - nasa_calculation).num_seconds();
 
             println!("{} - {} = {}", event_time, nasa_calculation, diff);
-            // The difference should be less than 20 minutes. Note that the NASA
-            // numbers are not observations but another set of calculations.
-            assert!(diff.abs() <= 1200);
+            // The difference should be less than 20 minutes once the drift of the
+            // table is removed. Note that the NASA numbers are not observations but
+            // another set of calculations, based on a fixed tropical year without
+            // any periodic terms.
+            let residual = nasa_residual(nasa_calculation.year(), diff, average_difference);
+            assert!(residual.abs() <= 1200.0);
         }
     }
 
@@ -975,9 +1283,12 @@ mod tests {
             let diff = (event_time - nasa_calculation).num_seconds();
 
             println!("{} - {} = {}", event_time, nasa_calculation, diff);
-            // The difference should be less than 20 minutes. Note that the NASA
-            // numbers are not observations but another set of calculations.
-            assert!(diff.abs() <= 1200);
+            // The difference should be less than 20 minutes once the drift of the
+            // table is removed. Note that the NASA numbers are not observations but
+            // another set of calculations, based on a fixed tropical year without
+            // any periodic terms.
+            let residual = nasa_residual(nasa_calculation.year(), diff, average_difference);
+            assert!(residual.abs() <= 1200.0);
         }
     }
 
@@ -985,10 +1296,10 @@ mod tests {
     fn test_annual_solar_events_2000() -> Result<(), Box<dyn Error>> {
         let events = AnnualSolarEvents::for_year(2000)?;
 
-        let march_equinox_str = "2000-03-20T07:30:00Z";
-        let june_solstice_str = "2000-06-21T01:44:00Z";
-        let september_equinox_str = "2000-09-22T17:16:00Z";
-        let december_solstice_str = "2000-12-21T13:27:00Z";
+        let march_equinox_str = "2000-03-20T07:35:00Z";
+        let june_solstice_str = "2000-06-21T01:48:00Z";
+        let september_equinox_str = "2000-09-22T17:27:00Z";
+        let december_solstice_str = "2000-12-21T13:37:00Z";
 
         let march_equinox_diff = (events.march_equinox().date_time()
             - DateTime::parse_from_rfc3339(march_equinox_str)?.to_utc())
@@ -1024,14 +1335,101 @@ mod tests {
             december_solstice_diff
         );
 
-        assert!(march_equinox_diff <= 400);
-        assert!(june_solstice_diff <= 400);
-        assert!(september_equinox_diff <= 400);
-        assert!(december_solstice_diff <= 400);
+        assert!(march_equinox_diff <= 60);
+        assert!(june_solstice_diff <= 60);
+        assert!(september_equinox_diff <= 60);
+        assert!(december_solstice_diff <= 60);
 
         assert_eq!(events.year(), 2000);
         Ok(())
     }
+
+    #[test]
+    fn mean_event_polynomials_match_meeus_table_27_b() {
+        assert_eq!(
+            MarchEquinox::julian_day_constants(),
+            (2451623.80984, 365242.37404, 0.05169, -0.00411, -0.00057)
+        );
+        assert_eq!(
+            JuneSolstice::julian_day_constants(),
+            (2451716.56767, 365241.62603, 0.00325, 0.00888, -0.00030)
+        );
+        assert_eq!(
+            SeptemberEquinox::julian_day_constants(),
+            (2451810.21715, 365242.01767, -0.11575, 0.00337, 0.00078)
+        );
+        assert_eq!(
+            DecemberSolstice::julian_day_constants(),
+            (2451900.05952, 365242.74049, -0.06223, -0.00823, 0.00032)
+        );
+    }
+
+    #[test]
+    fn june_solstice_of_1962_matches_meeus_example_27_a() {
+        let event = JuneSolstice::for_year(1962).unwrap();
+
+        assert_eq!(event.julian_ephemeris_day(), 2437837.39245);
+    }
+
+    // Published instants from the U.S. Naval Observatory, in UT and rounded to the minute.
+    const USNO_2010: [&str; 4] = [
+        "2010-03-20T17:32:00Z",
+        "2010-06-21T11:28:00Z",
+        "2010-09-23T03:09:00Z",
+        "2010-12-21T23:38:00Z",
+    ];
+    const USNO_2024: [&str; 4] = [
+        "2024-03-20T03:06:00Z",
+        "2024-06-20T20:51:00Z",
+        "2024-09-22T12:44:00Z",
+        "2024-12-21T09:20:00Z",
+    ];
+
+    fn match_annual_solar_events_to_usno(
+        year: i32,
+        usno_strs: [&str; 4],
+        method: CalculationMethod,
+        max_diff: f64,
+    ) {
+        for (kind, usno_str) in SolarEventKind::ALL.into_iter().zip(usno_strs) {
+            let event_time = SolarEvent::for_year_with_method(kind, year, method)
+                .unwrap()
+                .date_time();
+            let usno: DateTime<Utc> = DateTime::parse_from_rfc3339(usno_str).unwrap().into();
+            let diff = (event_time - usno).as_seconds_f64();
+
+            println!("{} - {} = {}", event_time, usno, diff);
+            assert!(diff.abs() <= max_diff);
+        }
+    }
+
+    #[test]
+    fn annual_solar_events_of_2010_are_within_a_minute_of_usno() {
+        match_annual_solar_events_to_usno(2010, USNO_2010, CalculationMethod::Meeus, 60.0);
+    }
+
+    #[test]
+    fn annual_solar_events_of_2024_are_within_a_minute_of_usno() {
+        match_annual_solar_events_to_usno(2024, USNO_2024, CalculationMethod::Meeus, 60.0);
+    }
+
+    // The truncated VSOP87 series are accurate to a few seconds, so the instants are within the
+    // 30 seconds of rounding of the published minute, with a few seconds to spare.
+    #[test]
+    fn vsop87_events_of_2010_and_2024_round_to_within_seconds_of_usno() {
+        match_annual_solar_events_to_usno(
+            2010,
+            USNO_2010,
+            CalculationMethod::TruncatedVsop87,
+            35.0,
+        );
+        match_annual_solar_events_to_usno(
+            2024,
+            USNO_2024,
+            CalculationMethod::TruncatedVsop87,
+            35.0,
+        );
+    }
 }
 
 // https://data.giss.nasa.gov/modelE/ar5plots/srvernal.html
@@ -0,0 +1,256 @@
+#[cfg(test)]
+mod tests {
+    use std::error::Error;
+
+    use chrono::{DateTime, Datelike, NaiveDate, NaiveTime, TimeDelta, Utc};
+    use solar_calendar_events::*;
+
+    #[test]
+    fn get_matches_the_accessors() -> Result<(), Box<dyn Error>> {
+        let events = AnnualSolarEvents::for_year(2024)?;
+
+        let march = events.get(SolarEventKind::MarchEquinox);
+        assert_eq!(march.date_time(), events.march_equinox().date_time());
+        assert_eq!(march.julian_day(), events.march_equinox().julian_day());
+        assert_eq!(
+            march.julian_ephemeris_day(),
+            events.march_equinox().julian_ephemeris_day()
+        );
+        assert_eq!(
+            events.get(SolarEventKind::JuneSolstice).date_time(),
+            events.june_solstice().date_time()
+        );
+        assert_eq!(
+            events.get(SolarEventKind::SeptemberEquinox).date_time(),
+            events.september_equinox().date_time()
+        );
+        assert_eq!(
+            events.get(SolarEventKind::DecemberSolstice).date_time(),
+            events.december_solstice().date_time()
+        );
+
+        for kind in SolarEventKind::ALL {
+            let event = SolarEvent::for_year(kind, 2024)?;
+            println!("{}: {}", kind, event.date_time());
+            assert_eq!(event, events.get(kind));
+            assert_eq!(event.year(), 2024);
+            let longitude = event.sun_position().longitude();
+            let difference = (longitude - kind.solar_longitude() + 180.0).rem_euclid(360.0) - 180.0;
+            assert!(difference.abs() < 0.01, "{kind}: {longitude}");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn iter_is_chronological() -> Result<(), Box<dyn Error>> {
+        let events = AnnualSolarEvents::for_year(1999)?;
+        let kinds: Vec<SolarEventKind> = events.iter().map(|event| event.kind()).collect();
+        assert_eq!(kinds, SolarEventKind::ALL);
+
+        let mut previous = None;
+        for event in &events {
+            if let Some(previous) = previous {
+                assert!(event.date_time() > previous);
+            }
+            previous = Some(event.date_time());
+        }
+        assert_eq!(events.into_iter().count(), 4);
+        Ok(())
+    }
+
+    #[test]
+    fn kinds_parse_from_their_names() {
+        for kind in SolarEventKind::ALL {
+            assert_eq!(kind.name().parse::<SolarEventKind>(), Ok(kind));
+            assert_eq!(kind.to_string().to_uppercase().parse(), Ok(kind));
+        }
+        assert_eq!("march_equinox".parse(), Ok(SolarEventKind::MarchEquinox));
+        assert_eq!(
+            "DecemberSolstice".parse(),
+            Ok(SolarEventKind::DecemberSolstice)
+        );
+        assert_eq!(
+            "Spring Equinox".parse::<SolarEventKind>(),
+            Err(AnnualSolarEventError::UnknownSolarEventKind(
+                "Spring Equinox".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn between_crosses_year_boundaries() -> Result<(), Box<dyn Error>> {
+        let start = AnnualSolarEvents::for_year(2019)?
+            .june_solstice()
+            .date_time();
+        let end: DateTime<Utc> = "2024-01-01T00:00:00Z".parse()?;
+
+        let mut expected = Vec::new();
+        for year in 2019..=2023 {
+            expected.extend(AnnualSolarEvents::for_year(year)?.iter());
+        }
+        expected.retain(|event| event.date_time() >= start);
+
+        let events: Vec<SolarEvent> = SolarEventIter::between(start, end)?.collect();
+        assert_eq!(events, expected);
+        assert_eq!(events[0].kind(), SolarEventKind::JuneSolstice);
+
+        let mut reversed: Vec<SolarEvent> = SolarEventIter::between(start, end)?.rev().collect();
+        reversed.reverse();
+        assert_eq!(reversed, expected);
+
+        // Taking from both ends meets in the middle without repeating events.
+        let mut iter = SolarEventIter::between(start, end)?;
+        let mut front = Vec::new();
+        let mut back = Vec::new();
+        loop {
+            match (iter.next(), iter.next_back()) {
+                (Some(first), Some(last)) => {
+                    front.push(first);
+                    back.push(last);
+                }
+                (Some(first), None) => front.push(first),
+                _ => break,
+            }
+        }
+        back.reverse();
+        front.extend(back);
+        assert_eq!(front, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn between_handles_empty_ranges_and_errors() -> Result<(), Box<dyn Error>> {
+        let start: DateTime<Utc> = "2024-04-01T00:00:00Z".parse()?;
+        let end: DateTime<Utc> = "2024-06-01T00:00:00Z".parse()?;
+        assert_eq!(SolarEventIter::between(start, end)?.count(), 0);
+        assert_eq!(SolarEventIter::between(end, start)?.next_back(), None);
+
+        let solstice = SolarEvent::for_year(SolarEventKind::DecemberSolstice, 2024)?;
+        let mut iter = SolarEventIter::between(solstice.date_time(), solstice.date_time())?;
+        assert_eq!(iter.next(), None);
+        let later = solstice.date_time() + TimeDelta::seconds(1);
+        assert_eq!(
+            SolarEventIter::between(solstice.date_time(), later)?.collect::<Vec<_>>(),
+            [solstice]
+        );
+
+        let far: DateTime<Utc> = "3001-01-01T00:00:00Z".parse()?;
+        assert_eq!(
+            SolarEventIter::between(start, far).err(),
+            Some(AnnualSolarEventError::YearOutOfRange(3001))
+        );
+        let events =
+            SolarEventIter::between_with_method(start, far, CalculationMethod::TruncatedVsop87)?;
+        assert_eq!(events.take(3).count(), 3);
+        Ok(())
+    }
+
+    fn start_of_year(year: i32) -> DateTime<Utc> {
+        NaiveDate::from_ymd_opt(year, 1, 1)
+            .unwrap()
+            .and_time(NaiveTime::MIN)
+            .and_utc()
+    }
+
+    #[test]
+    fn between_reaches_both_ends_of_the_supported_range() -> Result<(), Box<dyn Error>> {
+        for method in [CalculationMethod::Meeus, CalculationMethod::TruncatedVsop87] {
+            let (first, last) = method.year_range().into_inner();
+            let ranges = [
+                (start_of_year(first), start_of_year(first + 2)),
+                // Starts after the first year, whose events must not be included.
+                (start_of_year(first + 1), start_of_year(first + 2)),
+                (
+                    start_of_year(last - 1),
+                    start_of_year(last) + TimeDelta::days(364),
+                ),
+            ];
+
+            for (start, end) in ranges {
+                let mut expected = Vec::new();
+                for year in (start.year() - 1)..=(end.year() + 1) {
+                    if !method.year_range().contains(&year) {
+                        continue;
+                    }
+                    for kind in SolarEventKind::ALL {
+                        let event = SolarEvent::for_year_with_method(kind, year, method)?;
+                        if (start..end).contains(&event.date_time()) {
+                            expected.push(event);
+                        }
+                    }
+                }
+                let forward: Vec<SolarEvent> =
+                    SolarEventIter::between_with_method(start, end, method)?.collect();
+                let mut backward: Vec<SolarEvent> =
+                    SolarEventIter::between_with_method(start, end, method)?
+                        .rev()
+                        .collect();
+                backward.reverse();
+
+                println!("{:?} {} {}: {}", method, start, end, forward.len());
+                assert!(forward.len() >= 3);
+                assert_eq!(forward, expected);
+                assert_eq!(backward, expected);
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn next_and_previous_events_wrap_around_the_year() -> Result<(), Box<dyn Error>> {
+        let start: DateTime<Utc> = "2022-11-01T00:00:00Z".parse()?;
+        let end: DateTime<Utc> = "2025-06-01T00:00:00Z".parse()?;
+        let events: Vec<SolarEvent> = SolarEventIter::between(start, end)?.collect();
+
+        let mut date_time: DateTime<Utc> = "2023-01-01T00:00:00Z".parse()?;
+        while date_time.year() < 2025 {
+            let (next, until) = next_solar_event_after(date_time, None)?;
+            let (previous, since) = previous_solar_event_before(date_time, None)?;
+            let index = events.iter().position(|event| *event == next).unwrap();
+
+            assert!(next.date_time() > date_time);
+            assert_eq!(events[index - 1], previous);
+            assert_eq!(until, next.date_time() - date_time);
+            assert_eq!(since, date_time - previous.date_time());
+            date_time += TimeDelta::days(5);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn next_and_previous_events_filter_by_kind() -> Result<(), Box<dyn Error>> {
+        let solstice = SolarEvent::for_year(SolarEventKind::DecemberSolstice, 2024)?;
+        println!("December Solstice: {}", solstice.date_time());
+
+        // The search is strictly after or before the instant.
+        let (next, _) = next_solar_event_after(solstice.date_time(), None)?;
+        assert_eq!(next.kind(), SolarEventKind::MarchEquinox);
+        assert_eq!(next.year(), 2025);
+        let (previous, _) = previous_solar_event_before(solstice.date_time(), None)?;
+        assert_eq!(previous.kind(), SolarEventKind::SeptemberEquinox);
+
+        let kind = Some(SolarEventKind::DecemberSolstice);
+        let (next, until) = next_solar_event_after(solstice.date_time(), kind)?;
+        assert_eq!(
+            next,
+            SolarEvent::for_year(SolarEventKind::DecemberSolstice, 2025)?
+        );
+        assert!((364..=366).contains(&until.num_days()));
+        let (previous, _) = previous_solar_event_before(solstice.date_time(), kind)?;
+        assert_eq!(previous.year(), 2023);
+
+        let after = solstice.date_time() + TimeDelta::seconds(1);
+        assert_eq!(previous_solar_event_before(after, kind)?.0, solstice);
+
+        let june = Some(SolarEventKind::JuneSolstice);
+        let (previous, since) = previous_solar_event_before("2024-03-01T00:00:00Z".parse()?, june)?;
+        assert_eq!(previous.year(), 2023);
+        assert!(since > TimeDelta::days(250));
+
+        assert_eq!(
+            next_solar_event_after("3000-12-25T00:00:00Z".parse()?, None).err(),
+            Some(AnnualSolarEventError::YearOutOfRange(3001))
+        );
+        Ok(())
+    }
+}
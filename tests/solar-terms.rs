@@ -0,0 +1,120 @@
+#[cfg(test)]
+mod tests {
+    use std::error::Error;
+
+    use chrono::{DateTime, NaiveDate};
+    use solar_calendar_events::*;
+
+    #[test]
+    fn solar_terms_of_2024_match_the_chinese_calendar() -> Result<(), Box<dyn Error>> {
+        // Civil dates in China Standard Time, as published by the Hong Kong Observatory.
+        let dates = [
+            "2024-01-06",
+            "2024-01-20",
+            "2024-02-04",
+            "2024-02-19",
+            "2024-03-05",
+            "2024-03-20",
+            "2024-04-04",
+            "2024-04-19",
+            "2024-05-05",
+            "2024-05-20",
+            "2024-06-05",
+            "2024-06-21",
+            "2024-07-06",
+            "2024-07-22",
+            "2024-08-07",
+            "2024-08-22",
+            "2024-09-07",
+            "2024-09-22",
+            "2024-10-08",
+            "2024-10-23",
+            "2024-11-07",
+            "2024-11-22",
+            "2024-12-06",
+            "2024-12-21",
+        ];
+        let terms = SolarTerms::for_year(2024)?;
+
+        assert_eq!(terms.year(), 2024);
+        assert_eq!(terms.iter().count(), 24);
+        for (event, date) in terms.iter().zip(dates) {
+            println!("{}: {}", event.term().name(), event.china_standard_time());
+            assert_eq!(event.civil_date(), date.parse::<NaiveDate>()?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn solar_terms_are_chronological_and_at_their_solar_longitude() -> Result<(), Box<dyn Error>> {
+        for year in [-1000, 1000, 2024, 3000] {
+            let terms = SolarTerms::for_year(year)?;
+            let events: Vec<_> = terms.into_iter().collect();
+
+            assert_eq!(events[0].term(), SolarTerm::Xiaohan);
+            assert_eq!(events[23].term(), SolarTerm::Dongzhi);
+            assert!(events
+                .windows(2)
+                .all(|pair| pair[0].date_time() < pair[1].date_time()));
+            for event in events {
+                let position = SunPosition::at_julian_ephemeris_day(event.julian_ephemeris_day())?;
+                let diff = (position.longitude() - event.term().solar_longitude() + 180.0)
+                    .rem_euclid(360.0)
+                    - 180.0;
+
+                // The equinoxes and solstices use the Meeus tables, the other terms VSOP87.
+                assert!(diff.abs() < 0.002, "{} {:?}: {}", year, event.term(), diff);
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn solar_terms_match_annual_solar_events() -> Result<(), Box<dyn Error>> {
+        for method in [CalculationMethod::Meeus, CalculationMethod::TruncatedVsop87] {
+            let terms = SolarTerms::for_year_with_method(2024, method)?;
+
+            assert_eq!(
+                terms.get(SolarTerm::Chunfen).date_time(),
+                MarchEquinox::for_year_with_method(2024, method)?.date_time()
+            );
+            assert_eq!(
+                terms.get(SolarTerm::Xiazhi).date_time(),
+                JuneSolstice::for_year_with_method(2024, method)?.date_time()
+            );
+            assert_eq!(
+                terms.get(SolarTerm::Qiufen).date_time(),
+                SeptemberEquinox::for_year_with_method(2024, method)?.date_time()
+            );
+            assert_eq!(
+                terms.get(SolarTerm::Dongzhi).date_time(),
+                DecemberSolstice::for_year_with_method(2024, method)?.date_time()
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn lichun_2024_is_at_16_27_china_standard_time() -> Result<(), Box<dyn Error>> {
+        let lichun = *SolarTerms::for_year(2024)?.get(SolarTerm::Lichun);
+        let expected = DateTime::parse_from_rfc3339("2024-02-04T16:27:00+08:00")?;
+
+        assert_eq!(lichun.term().chinese_name(), "立春");
+        assert!(
+            (lichun.china_standard_time() - expected)
+                .num_seconds()
+                .abs()
+                <= 60
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn solar_terms_outside_the_year_range_are_an_error() {
+        assert_eq!(
+            SolarTerms::for_year(3001).err(),
+            Some(AnnualSolarEventError::YearOutOfRange(3001))
+        );
+        assert!(SolarTerms::for_year_with_method(6000, CalculationMethod::TruncatedVsop87).is_ok());
+    }
+}
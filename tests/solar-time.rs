@@ -0,0 +1,61 @@
+#[cfg(test)]
+mod tests {
+    use std::error::Error;
+
+    use chrono::{DateTime, NaiveDate, NaiveDateTime, TimeDelta, Utc};
+    use solar_calendar_events::*;
+
+    #[test]
+    fn equation_of_time_matches_meeus_example() -> Result<(), Box<dyn Error>> {
+        // Meeus, example 28.b: 13m42.6s on 1992 October 13 at 0h TD, about a minute before 0h UT.
+        let date_time: DateTime<Utc> = "1992-10-12T23:59:00Z".parse()?;
+        let expected = TimeDelta::milliseconds(13 * 60_000 + 42_600);
+
+        assert!((equation_of_time(date_time)? - expected).abs() < TimeDelta::milliseconds(500));
+        Ok(())
+    }
+
+    #[test]
+    fn local_solar_time_round_trips() -> Result<(), Box<dyn Error>> {
+        let date_time: DateTime<Utc> = "2024-07-26T09:30:15Z".parse()?;
+
+        for longitude in [-179.9, -74.0, 0.0, 18.07, 151.2] {
+            let mean = local_mean_time(date_time, longitude);
+            let apparent = local_apparent_time(date_time, longitude)?;
+
+            assert_eq!(utc_from_local_mean_time(mean, longitude), date_time);
+            assert!(
+                (utc_from_local_apparent_time(apparent, longitude)? - date_time).abs()
+                    < TimeDelta::microseconds(1)
+            );
+            assert_eq!(apparent - mean, equation_of_time(date_time)?);
+        }
+
+        // 18.07° east is 1h12m16.8s ahead of Greenwich.
+        let stockholm: NaiveDateTime = "2024-07-26T10:42:31.800".parse()?;
+        assert_eq!(local_mean_time(date_time, 18.07), stockholm);
+        Ok(())
+    }
+
+    #[test]
+    fn apparent_noon_is_the_solar_transit() -> Result<(), Box<dyn Error>> {
+        for (latitude, longitude) in [(59.33, 18.07), (-33.87, 151.21), (40.71, -74.01)] {
+            let observer = Observer::new(latitude, longitude, 0.0);
+            for month in 1..=12 {
+                let date = NaiveDate::from_ymd_opt(2024, month, 1).unwrap();
+                let noon = date.and_hms_opt(12, 0, 0).unwrap();
+                let transit = observer.solar_day(date)?.transit();
+
+                assert!(
+                    (utc_from_local_apparent_time(noon, longitude)? - transit).abs()
+                        < TimeDelta::seconds(1)
+                );
+            }
+        }
+        assert_eq!(
+            equation_of_time("6001-01-01T00:00:00Z".parse()?).err(),
+            Some(AnnualSolarEventError::YearOutOfRange(6001))
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,80 @@
+#[cfg(test)]
+mod tests {
+    use std::error::Error;
+
+    use chrono::{DateTime, TimeDelta, Utc};
+    use solar_calendar_events::*;
+
+    #[test]
+    fn sun_position_matches_meeus_examples_25_b_and_28_b() -> Result<(), Box<dyn Error>> {
+        // 1992 October 13.0 TD.
+        let position = SunPosition::at_julian_ephemeris_day(2448908.5)?;
+
+        println!("{:?}", position);
+        // λ = 199°54'21.818", β = +0.72", R = 0.99760775.
+        assert!((position.longitude() - 199.906_060_6).abs() * 3_600.0 < 0.1);
+        assert!((position.latitude() - 0.72 / 3_600.0).abs() * 3_600.0 < 0.5);
+        assert!((position.distance() - 0.997_607_75).abs() < 1e-7);
+        // α = 13h13m30.749s, δ = -7°47'01.74".
+        let right_ascension = (13.0 + 13.0 / 60.0 + 30.749 / 3_600.0) * 15.0;
+        let declination = -(7.0 + 47.0 / 60.0 + 1.74 / 3_600.0);
+        // The truncated VSOP87 differs by a fraction of an arcsecond from the full theory.
+        assert!((position.right_ascension() - right_ascension).abs() * 3_600.0 < 0.5);
+        assert!((position.declination() - declination).abs() * 3_600.0 < 0.5);
+        // E = 13m42.6s.
+        let diff = position.equation_of_time() - TimeDelta::milliseconds(822_600);
+        assert!(diff.num_milliseconds().abs() < 100);
+        Ok(())
+    }
+
+    #[test]
+    fn sun_position_at_converts_universal_time_with_delta_t() -> Result<(), Box<dyn Error>> {
+        let jde = 2448908.5;
+        let date_time = DateTime::<Utc>::from_julian_day(jde - delta_t(1992.78) / 86_400.0)?;
+        let position = SunPosition::at(date_time)?;
+        let expected = SunPosition::at_julian_ephemeris_day(jde)?;
+
+        assert!((position.longitude() - expected.longitude()).abs() * 3_600.0 < 0.01);
+        Ok(())
+    }
+
+    #[test]
+    fn sun_position_outside_vsop87_range_is_an_error() {
+        let date_time = DateTime::parse_from_rfc3339("6001-01-01T00:00:00Z")
+            .unwrap()
+            .to_utc();
+
+        assert_eq!(
+            SunPosition::at(date_time),
+            Err(AnnualSolarEventError::YearOutOfRange(6001))
+        );
+    }
+
+    #[test]
+    fn events_report_their_own_sun_position() -> Result<(), Box<dyn Error>> {
+        for year in [-1000, 1000, 2024, 3000] {
+            let events = AnnualSolarEvents::for_year(year)?;
+            let positions = [
+                events.march_equinox().sun_position(),
+                events.june_solstice().sun_position(),
+                events.september_equinox().sun_position(),
+                events.december_solstice().sun_position(),
+            ];
+
+            for (position, longitude) in positions.iter().zip([0.0, 90.0, 180.0, 270.0]) {
+                let diff = (position.longitude() - longitude + 180.0).rem_euclid(360.0) - 180.0;
+
+                println!("{} {}: {:?}", year, longitude, position);
+                // Within the accuracy of the Meeus method, 0.002° being about three minutes of
+                // the Sun's motion.
+                assert!(diff.abs() < 0.002);
+            }
+            // The declination is zero at the equinoxes and at its extremes at the solstices.
+            assert!(positions[0].declination().abs() < 0.002);
+            assert!(positions[1].declination() > 23.0);
+            assert!(positions[2].declination().abs() < 0.002);
+            assert!(positions[3].declination() < -23.0);
+        }
+        Ok(())
+    }
+}
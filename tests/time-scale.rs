@@ -0,0 +1,132 @@
+#[cfg(test)]
+mod tests {
+    use std::error::Error;
+
+    use solar_calendar_events::*;
+
+    const SAMPLE_LEAP_SECONDS_LIST: &str = "\
+#	Updated through IERS Bulletin C
+#$	 3929093563
+#@	3960057600
+#
+2272060800	10	# 1 Jan 1972
+2287785600	11	# 1 Jul 1972
+2303683200	12	# 1 Jan 1973
+3644697600	36	# 1 Jul 2015
+3692217600	37	# 1 Jan 2017
+#h	16edd0f0 3666784f 37db6bdd e74ced87 59af48f1
+";
+
+    // 2020-06-01T00:00:00Z
+    const JUNE_2020: f64 = 2_459_001.5;
+
+    #[test]
+    fn built_in_table_has_37_leap_seconds_in_2020() {
+        let table = LeapSecondTable::default();
+
+        assert_eq!(table.tai_minus_utc(JUNE_2020), 37.0);
+        assert_eq!(table.expires(), None);
+    }
+
+    #[test]
+    fn built_in_table_changes_at_the_leap_second() {
+        let table = LeapSecondTable::default();
+        // 2017-01-01T00:00:00Z
+        let new_year_2017 = 2_457_754.5;
+
+        assert_eq!(table.tai_minus_utc(new_year_2017 - 1e-6), 36.0);
+        assert_eq!(table.tai_minus_utc(new_year_2017), 37.0);
+        assert_eq!(table.tai_minus_utc(2_441_317.5), 10.0);
+    }
+
+    #[test]
+    fn built_in_table_drifts_before_1972() {
+        let table = LeapSecondTable::default();
+
+        // MJD 38761 (1965-01-01) is the reference date of the 1965 entry.
+        assert!((table.tai_minus_utc(2_438_761.5) - 3.540_13).abs() < 1e-9);
+        // MJD 40587 (1970-01-01).
+        let expected = 4.213_17 + (40_587.0 - 39_126.0) * 0.002_592;
+        assert!((table.tai_minus_utc(2_440_587.5) - expected).abs() < 1e-9);
+    }
+
+    #[test]
+    fn converts_utc_to_other_time_scales() {
+        let table = LeapSecondTable::default();
+        let seconds = |to| (table.convert(JUNE_2020, TimeScale::Utc, to) - JUNE_2020) * 86_400.0;
+
+        assert!((seconds(TimeScale::Utc)).abs() < 1e-9);
+        assert!((seconds(TimeScale::Tai) - 37.0).abs() < 1e-4);
+        assert!((seconds(TimeScale::Tt) - 69.184).abs() < 1e-4);
+        assert!((seconds(TimeScale::Gps) - 18.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn conversions_round_trip() {
+        let table = LeapSecondTable::default();
+        let scales = [
+            TimeScale::Utc,
+            TimeScale::Tai,
+            TimeScale::Tt,
+            TimeScale::Gps,
+        ];
+
+        for julian_day in [2_437_400.25, 2_440_000.5, 2_451_545.0, JUNE_2020] {
+            for from in scales {
+                for to in scales {
+                    let converted = table.convert(julian_day, from, to);
+                    let back = table.convert(converted, to, from);
+
+                    println!("{} {:?} -> {:?}: {}", julian_day, from, to, back);
+                    assert!((back - julian_day).abs() * 86_400.0 < 1e-4);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn parses_leap_seconds_list() -> Result<(), Box<dyn Error>> {
+        let table = LeapSecondTable::parse_leap_seconds_list(SAMPLE_LEAP_SECONDS_LIST)?;
+
+        // 2025-06-28T00:00:00Z
+        assert_eq!(table.expires(), Some(2_460_854.5));
+        assert_eq!(table.tai_minus_utc(2_441_317.5), 10.0);
+        assert_eq!(table.tai_minus_utc(2_441_499.5), 11.0);
+        assert_eq!(table.tai_minus_utc(2_457_204.5), 36.0);
+        assert_eq!(table.tai_minus_utc(JUNE_2020), 37.0);
+        // Before the first entry, the first offset is used.
+        assert_eq!(table.tai_minus_utc(2_430_000.5), 10.0);
+        Ok(())
+    }
+
+    #[test]
+    fn rejects_invalid_leap_seconds_list() {
+        assert_eq!(
+            LeapSecondTable::parse_leap_seconds_list("2272060800\t10\n2287785600\televen\n"),
+            Err(AnnualSolarEventError::LeapSecondParseError(2))
+        );
+        assert_eq!(
+            LeapSecondTable::parse_leap_seconds_list("# only comments\n"),
+            Err(AnnualSolarEventError::LeapSecondParseError(0))
+        );
+    }
+
+    #[test]
+    fn loads_leap_seconds_list_from_disk() -> Result<(), Box<dyn Error>> {
+        let path = std::env::temp_dir().join(format!(
+            "solar-calendar-events-leap-seconds-{}.list",
+            std::process::id()
+        ));
+        std::fs::write(&path, SAMPLE_LEAP_SECONDS_LIST)?;
+
+        let table = LeapSecondTable::load(&path)?;
+        std::fs::remove_file(&path)?;
+
+        assert_eq!(table.tai_minus_utc(JUNE_2020), 37.0);
+        assert!(matches!(
+            LeapSecondTable::load(path),
+            Err(AnnualSolarEventError::LeapSecondFileError(_))
+        ));
+        Ok(())
+    }
+}
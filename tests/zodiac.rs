@@ -0,0 +1,132 @@
+#[cfg(test)]
+mod tests {
+    use std::error::Error;
+
+    use chrono::{DateTime, Datelike};
+    use solar_calendar_events::*;
+
+    fn assert_at_longitude(
+        ingresses: &ZodiacIngresses,
+        max_diff: f64,
+    ) -> Result<(), Box<dyn Error>> {
+        for ingress in ingresses {
+            let jde = ingress.julian_ephemeris_day();
+            let ayanamsa = match ingresses.zodiac() {
+                Zodiac::Tropical => 0.0,
+                Zodiac::Sidereal(ayanamsa) => ayanamsa.degrees(jde),
+            };
+            let longitude = SunPosition::at_julian_ephemeris_day(jde)?.longitude() - ayanamsa;
+            let diff = (longitude - ingress.sign().longitude() + 180.0).rem_euclid(360.0) - 180.0;
+
+            println!(
+                "{}: {} ({})",
+                ingress.sign().name(),
+                ingress.date_time(),
+                diff
+            );
+            assert!(diff.abs() < max_diff);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn tropical_ingresses_are_at_multiples_of_30_degrees() -> Result<(), Box<dyn Error>> {
+        for year in [-1000, 1000, 2024, 3000] {
+            let ingresses = ZodiacIngresses::for_year(year, Zodiac::Tropical)?;
+
+            assert_eq!(ingresses.year(), year);
+            assert_eq!(ingresses.iter().count(), 12);
+            assert!(ingresses
+                .iter()
+                .zip(ingresses.iter().skip(1))
+                .all(|(a, b)| a.date_time() < b.date_time()));
+            // The ingresses into Aries, Cancer, Libra and Capricorn use the Meeus tables.
+            assert_at_longitude(&ingresses, 0.002)?;
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn tropical_ingresses_match_annual_solar_events() -> Result<(), Box<dyn Error>> {
+        let ingresses = ZodiacIngresses::for_year(2024, Zodiac::Tropical)?;
+        let events = AnnualSolarEvents::for_year(2024)?;
+
+        assert_eq!(
+            ingresses.get(ZodiacSign::Aries).date_time(),
+            events.march_equinox().date_time()
+        );
+        assert_eq!(
+            ingresses.get(ZodiacSign::Cancer).date_time(),
+            events.june_solstice().date_time()
+        );
+        assert_eq!(
+            ingresses.get(ZodiacSign::Libra).date_time(),
+            events.september_equinox().date_time()
+        );
+        assert_eq!(
+            ingresses.get(ZodiacSign::Capricorn).date_time(),
+            events.december_solstice().date_time()
+        );
+        assert_eq!(ingresses.get(ZodiacSign::Pisces).date_time().year(), 2025);
+        Ok(())
+    }
+
+    #[test]
+    fn sidereal_ingresses_are_at_multiples_of_30_degrees() -> Result<(), Box<dyn Error>> {
+        for ayanamsa in [Ayanamsa::Lahiri, Ayanamsa::FaganBradley, Ayanamsa::Raman] {
+            for year in [-2000, -1000, 0, 1000, 2024, 3000, 5999] {
+                let ingresses = ZodiacIngresses::for_year_with_method(
+                    year,
+                    Zodiac::Sidereal(ayanamsa),
+                    CalculationMethod::TruncatedVsop87,
+                )?;
+
+                assert_eq!(ingresses.get(ZodiacSign::Aries).date_time().year(), year);
+                assert_at_longitude(&ingresses, 1e-6)?;
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn ayanamsas_match_their_definitions() {
+        // Lahiri is defined as 23°15′00.658″ at 1956 March 21, 0h ET, including nutation.
+        let lahiri = 23.0 + 15.0 / 60.0 + 0.658 / 3_600.0;
+        assert!((Ayanamsa::Lahiri.degrees(2_435_553.5) - lahiri).abs() * 3_600.0 < 0.1);
+
+        // Between 1950.0 and J2000.0 the mean ayanamsa grows by the general precession of
+        // 50.29″ a year. The nutation in longitude is at most 17.2″.
+        let fagan_bradley = 24.0 + 2.0 / 60.0 + 31.36 / 3_600.0 + 50.0 * 50.29 / 3_600.0;
+        assert!(
+            (Ayanamsa::FaganBradley.degrees(2_451_545.0) - fagan_bradley).abs() * 3_600.0 < 18.0
+        );
+        assert!(
+            (Ayanamsa::FaganBradley.degrees(2_451_545.0)
+                - Ayanamsa::Raman.degrees(2_451_545.0)
+                - (24.042_044_444 - 21.014_444 - 50.0 * 50.29 / 3_600.0))
+                .abs()
+                * 3_600.0
+                < 1.0
+        );
+
+        let custom = Ayanamsa::Custom {
+            julian_ephemeris_day: 2_451_545.0,
+            degrees: 24.0,
+        };
+        let century_later = custom.degrees(2_451_545.0 + 36_525.0) - custom.degrees(2_451_545.0);
+        assert!((century_later * 3_600.0 - 5_029.1).abs() < 2.0 * 17.2);
+    }
+
+    #[test]
+    fn mesha_sankranti_2024_is_on_april_13() -> Result<(), Box<dyn Error>> {
+        // The Sun entered sidereal Aries at 21:15 Indian Standard Time. The published times vary
+        // by several minutes between almanacs, depending on their ephemeris and ayanamsa model.
+        let ingresses = ZodiacIngresses::for_year(2024, Zodiac::Sidereal(Ayanamsa::Lahiri))?;
+        let expected = DateTime::parse_from_rfc3339("2024-04-13T21:15:00+05:30")?.to_utc();
+        let diff = ingresses.get(ZodiacSign::Aries).date_time() - expected;
+
+        println!("{} minutes", diff.num_minutes());
+        assert!(diff.num_minutes().abs() <= 15);
+        Ok(())
+    }
+}